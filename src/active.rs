@@ -0,0 +1,65 @@
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[cfg(not(target_os = "windows"))]
+const JAVA_EXECUTABLE: &str = "java";
+#[cfg(target_os = "windows")]
+const JAVA_EXECUTABLE: &str = "java.exe";
+
+/// Real JVM home named by a JAVA_HOME value
+pub fn java_home(value: &OsStr) -> Option<PathBuf> {
+    fs::canonicalize(value).ok().filter(|home| home.is_dir())
+}
+
+/// Real JVM home of the first java executable on a PATH value, following links such as
+/// /usr/bin/java -> /etc/alternatives/java -> /usr/lib/jvm/java-17-openjdk-amd64/bin/java
+pub fn path_java_home(path: &OsStr) -> Option<PathBuf> {
+    let java = env::split_paths(path)
+        .map(|dir| dir.join(JAVA_EXECUTABLE))
+        .find(|java| java.is_file())?;
+    executable_home(&fs::canonicalize(java).ok()?)
+}
+
+/// The home is the parent of bin - except for the JRE inside a Java 8 JDK, where the JDK is the home
+pub fn executable_home(java: &Path) -> Option<PathBuf> {
+    let home = java.parent()?.parent()?;
+    match home.parent() {
+        Some(jdk) if home.ends_with("jre") && jdk.join("release").is_file() => Some(jdk.to_path_buf()),
+        _ => Some(home.to_path_buf()),
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::fixture::TempDir;
+
+    #[test]
+    fn test_path_java_home() {
+        let root = TempDir::new("active");
+        let home = root.join("jvm/java-17-openjdk-amd64");
+        let jdk8 = root.join("jvm/java-8-openjdk-amd64");
+        fs::create_dir_all(home.join("bin")).unwrap();
+        fs::create_dir_all(jdk8.join("jre/bin")).unwrap();
+        fs::create_dir_all(root.join("bin")).unwrap();
+        fs::create_dir_all(root.join("alternatives")).unwrap();
+        fs::create_dir_all(root.join("empty")).unwrap();
+        fs::write(home.join("bin/java"), "").unwrap();
+        fs::write(jdk8.join("jre/bin/java"), "").unwrap();
+        fs::write(jdk8.join("release"), "").unwrap();
+        std::os::unix::fs::symlink(home.join("bin/java"), root.join("alternatives/java")).unwrap();
+        std::os::unix::fs::symlink(root.join("alternatives/java"), root.join("bin/java")).unwrap();
+
+        let path = env::join_paths([root.join("empty"), root.join("bin")]).unwrap();
+        assert_eq!(path_java_home(&path), Some(fs::canonicalize(&home).unwrap()));
+        let path = env::join_paths([jdk8.join("jre/bin")]).unwrap();
+        assert_eq!(path_java_home(&path), Some(fs::canonicalize(&jdk8).unwrap()));
+        let path = env::join_paths([root.join("empty")]).unwrap();
+        assert_eq!(path_java_home(&path), None);
+
+        assert_eq!(java_home(home.as_os_str()), Some(fs::canonicalize(&home).unwrap()));
+        assert_eq!(java_home(root.join("missing").as_os_str()), None);
+    }
+}
@@ -0,0 +1,103 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::report::ScanReport;
+
+// Where update-alternatives keeps the java group - dpkg on Debian and derivatives,
+// chkconfig's alternatives on Fedora, RHEL and SUSE
+const ALTERNATIVES_FILES: [&str; 2] = ["/var/lib/dpkg/alternatives/java", "/var/lib/alternatives/java"];
+
+// The link update-alternatives points at the chosen java
+const CURRENT_LINK: &str = "/etc/alternatives/java";
+
+/// A java executable registered with update-alternatives, and its priority
+#[derive(Clone, Debug, PartialEq)]
+pub struct Alternative {
+    pub path: PathBuf,
+    pub priority: i64,
+}
+
+/// Alternatives registered for java, from whichever alternatives database this system has
+pub fn java_alternatives(report: &mut ScanReport) -> Vec<Alternative> {
+    let mut alternatives = vec![];
+    for file in ALTERNATIVES_FILES {
+        match fs::read_to_string(file) {
+            Ok(content) => alternatives.append(&mut parse_alternatives(&content)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => report.location(file, error),
+        }
+    }
+    alternatives
+}
+
+/// The java executable update-alternatives has chosen as the system default
+pub fn current_alternative() -> Option<PathBuf> {
+    fs::canonicalize(CURRENT_LINK).ok()
+}
+
+/// Parses an alternatives group file. Both formats start with the mode, the link and each slave's
+/// name and link up to a blank line, followed by each alternative's path, priority and a line per
+/// slave. chkconfig may put a family name before the priority, on the same line.
+pub fn parse_alternatives(content: &str) -> Vec<Alternative> {
+    let mut lines = content.lines();
+    // Mode and link
+    lines.next();
+    lines.next();
+    let slaves = lines.by_ref().take_while(|line| !line.is_empty()).count() / 2;
+
+    let mut alternatives = vec![];
+    while let Some(path) = lines.next().filter(|line| !line.is_empty()) {
+        let priority = lines.next()
+            .and_then(|line| line.split_whitespace().last())
+            .and_then(|priority| priority.parse::<i64>().ok());
+        let Some(priority) = priority else {
+            break;
+        };
+        alternatives.push(Alternative { path: PathBuf::from(path), priority });
+        for _ in 0..slaves {
+            lines.next();
+        }
+    }
+    alternatives
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alternative(path: &str, priority: i64) -> Alternative {
+        Alternative { path: PathBuf::from(path), priority }
+    }
+
+    #[test]
+    fn test_parse_dpkg() {
+        let content = "auto\n/usr/bin/java\njava.1.gz\n/usr/share/man/man1/java.1.gz\njexec\n/usr/bin/jexec\n\n\
+                       /usr/lib/jvm/java-17-openjdk-amd64/bin/java\n1711\n/usr/lib/jvm/java-17-openjdk-amd64/man/man1/java.1.gz\n\n\
+                       /usr/lib/jvm/java-8-openjdk-amd64/jre/bin/java\n1081\n\n/usr/lib/jvm/java-8-openjdk-amd64/jre/lib/jexec\n\n";
+        assert_eq!(parse_alternatives(content), vec![
+            alternative("/usr/lib/jvm/java-17-openjdk-amd64/bin/java", 1711),
+            alternative("/usr/lib/jvm/java-8-openjdk-amd64/jre/bin/java", 1081),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_chkconfig() {
+        // As written by chkconfig's alternatives, with a family name before the first priority
+        let content = "manual\n/usr/bin/java\njre\n/usr/lib/jvm/jre\nkeytool\n/usr/bin/keytool\n\n\
+                       /usr/lib/jvm/java-17-openjdk-17.0.9.0.9-3.fc39.x86_64/bin/java\njava-17-openjdk.x86_64 17000009\n\
+                       /usr/lib/jvm/java-17-openjdk-17.0.9.0.9-3.fc39.x86_64\n/usr/lib/jvm/java-17-openjdk-17.0.9.0.9-3.fc39.x86_64/bin/keytool\n\
+                       /usr/lib/jvm/java-21-openjdk-21.0.1.0.12-1.fc39.x86_64/bin/java\n21000001\n\
+                       /usr/lib/jvm/java-21-openjdk-21.0.1.0.12-1.fc39.x86_64\n/usr/lib/jvm/java-21-openjdk-21.0.1.0.12-1.fc39.x86_64/bin/keytool\n";
+        assert_eq!(parse_alternatives(content), vec![
+            alternative("/usr/lib/jvm/java-17-openjdk-17.0.9.0.9-3.fc39.x86_64/bin/java", 17000009),
+            alternative("/usr/lib/jvm/java-21-openjdk-21.0.1.0.12-1.fc39.x86_64/bin/java", 21000001),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse_alternatives("").is_empty());
+        assert!(parse_alternatives("auto\n/usr/bin/java\n\n/usr/bin/java-wrapper\nhigh\nhigher\n").is_empty());
+    }
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+
+/// Canonical CPU architecture, normalised from the many names used by release files,
+/// package managers and the kernel (e.g. amd64, x64 and x86_64 are all X86_64)
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Architecture {
+    X86_64,
+    X86,
+    Aarch64,
+    Arm32,
+    Ppc64le,
+    S390x,
+    Riscv64,
+    Loongarch64,
+    Other(String),
+    #[default]
+    Unknown,
+}
+
+const ALIASES: [(&[&str], Architecture); 8] = [
+    (&["x86_64", "amd64", "x64", "x86-64"], Architecture::X86_64),
+    (&["x86", "i386", "i486", "i586", "i686", "ia32"], Architecture::X86),
+    (&["aarch64", "arm64"], Architecture::Aarch64),
+    (&["arm", "arm32", "armhf", "armel", "armv6l", "armv7", "armv7l", "aarch32"], Architecture::Arm32),
+    (&["ppc64le", "powerpc64le"], Architecture::Ppc64le),
+    (&["s390x"], Architecture::S390x),
+    (&["riscv64"], Architecture::Riscv64),
+    (&["loongarch64", "loong64"], Architecture::Loongarch64),
+];
+
+impl Architecture {
+    pub fn parse(value: &str) -> Architecture {
+        let value = value.trim().trim_matches('"').to_lowercase();
+        if value.is_empty() {
+            return Architecture::Unknown;
+        }
+        ALIASES.iter()
+            .find(|(aliases, _)| aliases.contains(&value.as_str()))
+            .map(|(_, architecture)| architecture.clone())
+            .unwrap_or(Architecture::Other(value))
+    }
+}
+
+impl fmt::Display for Architecture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Architecture::X86_64 => "x86_64",
+            Architecture::X86 => "x86",
+            Architecture::Aarch64 => "aarch64",
+            Architecture::Arm32 => "arm",
+            Architecture::Ppc64le => "ppc64le",
+            Architecture::S390x => "s390x",
+            Architecture::Riscv64 => "riscv64",
+            Architecture::Loongarch64 => "loongarch64",
+            Architecture::Other(name) => name,
+            Architecture::Unknown => "unknown",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_aliases() {
+        assert_eq!(Architecture::parse("amd64"), Architecture::X86_64);
+        assert_eq!(Architecture::parse("x86_64"), Architecture::X86_64);
+        assert_eq!(Architecture::parse("x64"), Architecture::X86_64);
+        assert_eq!(Architecture::parse("arm64"), Architecture::Aarch64);
+        assert_eq!(Architecture::parse("AArch64"), Architecture::Aarch64);
+        assert_eq!(Architecture::parse("i386"), Architecture::X86);
+        assert_eq!(Architecture::parse("i686"), Architecture::X86);
+        assert_eq!(Architecture::parse("armhf"), Architecture::Arm32);
+        assert_eq!(Architecture::parse("arm32"), Architecture::Arm32);
+        assert_eq!(Architecture::parse("ppc64le"), Architecture::Ppc64le);
+        assert_eq!(Architecture::parse("s390x"), Architecture::S390x);
+        assert_eq!(Architecture::parse("\"riscv64\""), Architecture::Riscv64);
+        assert_eq!(Architecture::parse("armv7l"), Architecture::Arm32);
+        assert_eq!(Architecture::parse("loongarch64"), Architecture::Loongarch64);
+    }
+
+    #[test]
+    fn test_parse_unrecognised() {
+        assert_eq!(Architecture::parse(""), Architecture::Unknown);
+        assert_eq!(Architecture::parse("SPARCV9"), Architecture::Other("sparcv9".to_string()));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Architecture::parse("amd64").to_string(), "x86_64");
+        assert_eq!(Architecture::parse("armhf").to_string(), "arm");
+        assert_eq!(Architecture::parse(&Architecture::Ppc64le.to_string()), Architecture::Ppc64le);
+    }
+}
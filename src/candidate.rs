@@ -0,0 +1,25 @@
+use std::path::PathBuf;
+
+use crate::arch::Architecture;
+use crate::vendor::Vendor;
+use crate::version::JavaVersion;
+
+/// A JVM home installed by a per-user tool, with whatever the tool's layout says about it
+/// - used to fill in the details when the home has no release file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Candidate {
+    pub path: PathBuf,
+    pub name: String,
+    pub version: String,
+    pub vendor: Vendor,
+    pub architecture: Architecture,
+    pub tags: Vec<String>,
+}
+
+/// The version if it can be understood, otherwise empty so the release file has to provide it
+pub fn java_version(version: &str) -> String {
+    match JavaVersion::parse(version) {
+        Some(_) => version.to_string(),
+        None => String::new(),
+    }
+}
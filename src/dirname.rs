@@ -0,0 +1,112 @@
+use crate::arch::Architecture;
+use crate::vendor::Vendor;
+use crate::version::JavaVersion;
+
+/// Details recovered from the name of a JVM home that has no release file
+#[derive(Debug, Default, PartialEq)]
+pub struct DirName {
+    pub version: String,
+    pub vendor: Vendor,
+    pub architecture: Architecture,
+}
+
+/// Best effort parse of a JVM home directory name, covering distribution package layouts
+/// (e.g. java-8-openjdk-amd64, java-17-openjdk-17.0.8.0.7-1.fc38.x86_64) and vendor archives
+/// (e.g. jdk1.8.0_202, zulu17.44.53-ca-jdk17.0.8.1-linux_x64). Names without a Java version
+/// give None.
+pub fn parse_dir_name(name: &str) -> Option<DirName> {
+    let name = name.strip_suffix(".jdk").unwrap_or(name);
+    let tokens: Vec<&str> = name.split('-').collect();
+
+    // A version straight after "jdk" or "java" is the Java version - other numbers may belong to
+    // the vendor's own release (e.g. the 17.44.53 of zulu17.44.53 or the 22.3.0 of GraalVM 22.3.0)
+    let mut anchor = None;
+    let mut bare = vec![];
+    for (index, token) in tokens.iter().enumerate() {
+        let marked = ["jdk", "java"].iter().find_map(|marker| token.strip_prefix(marker));
+        match marked {
+            Some("") => {
+                if let Some(version) = tokens.get(index + 1).and_then(|next| leading_version(next)) {
+                    anchor = anchor.or(Some(version));
+                }
+            }
+            Some(rest) => {
+                if let Some(version) = leading_version(rest) {
+                    anchor = anchor.or(Some(version));
+                }
+            }
+            None => bare.extend(leading_version(token)),
+        }
+    }
+
+    // Packages often give the feature release first and the full version later on
+    // - e.g. java-17-openjdk-17.0.8.0.7
+    let version = match anchor {
+        Some(anchor) => bare.into_iter()
+            .find(|version| version.starts_with(&format!("{}.", anchor)))
+            .unwrap_or(anchor),
+        None => bare.into_iter().next()?,
+    };
+
+    let vendor = match Vendor::detect(name, "", "") {
+        Vendor::Other(_) => Vendor::Unknown,
+        vendor => vendor,
+    };
+    let architecture = name.split(['-', '.'])
+        .flat_map(|token| [token, token.rsplit('_').next().unwrap_or_default()])
+        .map(Architecture::parse)
+        .find(|architecture| !matches!(architecture, Architecture::Other(_) | Architecture::Unknown))
+        .unwrap_or_default();
+
+    Some(DirName { version, vendor, architecture })
+}
+
+// The version number at the start of a token, ignoring anything after it
+// - e.g. 17.0.8.0.7 from 17.0.8.0.7 and 1 from 1.fc38.x86_64
+fn leading_version(token: &str) -> Option<String> {
+    let end = token.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '_')).unwrap_or(token.len());
+    let version = token[..end].trim_end_matches(['.', '_']);
+    if version.is_empty() || JavaVersion::parse(version).is_none() {
+        return None;
+    }
+    Some(version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dir_name(version: &str, vendor: Vendor, architecture: Architecture) -> Option<DirName> {
+        Some(DirName { version: version.to_string(), vendor, architecture })
+    }
+
+    #[test]
+    fn test_parse_package_layouts() {
+        assert_eq!(parse_dir_name("java-8-openjdk-amd64"), dir_name("8", Vendor::Unknown, Architecture::X86_64));
+        assert_eq!(parse_dir_name("java-11-openjdk-arm64"), dir_name("11", Vendor::Unknown, Architecture::Aarch64));
+        assert_eq!(parse_dir_name("java-17-openjdk-17.0.8.0.7-1.fc38.x86_64"),
+                   dir_name("17.0.8.0.7", Vendor::Unknown, Architecture::X86_64));
+        assert_eq!(parse_dir_name("java-1.8.0-openjdk-1.8.0.392.b08-4.el8.x86_64"),
+                   dir_name("1.8.0.392", Vendor::Unknown, Architecture::X86_64));
+    }
+
+    #[test]
+    fn test_parse_vendor_archives() {
+        assert_eq!(parse_dir_name("jdk-17.0.2"), dir_name("17.0.2", Vendor::Unknown, Architecture::Unknown));
+        assert_eq!(parse_dir_name("jdk1.8.0_202"), dir_name("1.8.0_202", Vendor::Unknown, Architecture::Unknown));
+        assert_eq!(parse_dir_name("zulu17.44.53-ca-jdk17.0.8.1-linux_x64"),
+                   dir_name("17.0.8.1", Vendor::Zulu, Architecture::X86_64));
+        assert_eq!(parse_dir_name("amazon-corretto-17.0.9.8.1-linux-x64"),
+                   dir_name("17.0.9.8.1", Vendor::Corretto, Architecture::X86_64));
+        assert_eq!(parse_dir_name("graalvm-ce-java17-22.3.0"), dir_name("17", Vendor::GraalVm, Architecture::Unknown));
+        assert_eq!(parse_dir_name("temurin-17.jdk"), dir_name("17", Vendor::Temurin, Architecture::Unknown));
+    }
+
+    #[test]
+    fn test_parse_unversioned() {
+        assert_eq!(parse_dir_name("default-java"), None);
+        assert_eq!(parse_dir_name("java"), None);
+        assert_eq!(parse_dir_name("jdk-"), None);
+        assert_eq!(parse_dir_name(""), None);
+    }
+}
@@ -0,0 +1,183 @@
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+
+// The lib/modules image starts with this magic, in the byte order of the platform it was built for
+const MAGIC: u32 = 0xCAFEDADA;
+const HEADER_SIZE: usize = 28;
+
+// Location attribute kinds, from jdk.internal.jimage.ImageLocation
+const ATTRIBUTE_END: u8 = 0;
+const ATTRIBUTE_MODULE: u8 = 1;
+const ATTRIBUTE_BASE: u8 = 3;
+const ATTRIBUTE_EXTENSION: u8 = 4;
+
+/// Modules in a jimage file such as lib/modules, reading only the index at the start of the
+/// file rather than the resources after it
+pub fn image_modules(path: &Path) -> io::Result<Vec<String>> {
+    let mut file = File::open(path)?;
+    let mut header = [0; HEADER_SIZE];
+    file.read_exact(&mut header)?;
+    let size = index_size(&header).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "not a jimage file"))?;
+
+    let mut index = header.to_vec();
+    file.take((size - HEADER_SIZE) as u64).read_to_end(&mut index)?;
+    parse_index(&index).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid jimage index"))
+}
+
+// The parts of the header needed to find the index tables
+struct Header {
+    read: fn([u8; 4]) -> u32,
+    table_length: usize,
+    locations_size: usize,
+    strings_size: usize,
+}
+
+impl Header {
+    // After the magic come the version, flags, resource count, table length, and the sizes of
+    // the location attributes and strings
+    fn parse(header: &[u8]) -> Option<Header> {
+        let magic: [u8; 4] = header.get(0..4)?.try_into().ok()?;
+        let read: fn([u8; 4]) -> u32 = if u32::from_le_bytes(magic) == MAGIC {
+            u32::from_le_bytes
+        } else if u32::from_be_bytes(magic) == MAGIC {
+            u32::from_be_bytes
+        } else {
+            return None;
+        };
+        let field = |index: usize| -> Option<usize> {
+            Some(read(header.get(index * 4..index * 4 + 4)?.try_into().ok()?) as usize)
+        };
+        Some(Header { read, table_length: field(4)?, locations_size: field(5)?, strings_size: field(6)? })
+    }
+}
+
+fn index_size(header: &[u8]) -> Option<usize> {
+    let header = Header::parse(header)?;
+    Some(HEADER_SIZE + header.table_length * 8 + header.locations_size + header.strings_size)
+}
+
+/// Modules listed in a jimage index - the modules with a module-info.class resource, as the
+/// index also has entries for the /modules and /packages directories
+pub fn parse_index(index: &[u8]) -> Option<Vec<String>> {
+    let Header { read, table_length, locations_size, strings_size } = Header::parse(index)?;
+    // The redirect table comes first, followed by each resource's offset into the locations
+    let offsets_start = HEADER_SIZE + table_length * 4;
+    let locations_start = offsets_start + table_length * 4;
+    let strings_start = locations_start + locations_size;
+    let locations = index.get(locations_start..strings_start)?;
+    let strings = index.get(strings_start..strings_start + strings_size)?;
+
+    let string = |offset: u64| -> Option<String> {
+        let value = strings.get(offset as usize..)?;
+        let end = value.iter().position(|byte| *byte == 0)?;
+        Some(String::from_utf8_lossy(&value[..end]).to_string())
+    };
+
+    let mut modules = vec![];
+    for entry in 0..table_length {
+        let start = offsets_start + entry * 4;
+        let offset = read(index.get(start..start + 4)?.try_into().ok()?) as usize;
+        let attributes = location_attributes(locations.get(offset..)?);
+        let value = |kind: u8| attributes.iter().find(|(attribute, _)| *attribute == kind).map(|(_, value)| *value);
+        let is_module_info = value(ATTRIBUTE_BASE).and_then(string).is_some_and(|base| base == "module-info")
+            && value(ATTRIBUTE_EXTENSION).and_then(string).is_some_and(|extension| extension == "class");
+        if let Some(module) = value(ATTRIBUTE_MODULE).and_then(string).filter(|_| is_module_info) {
+            if !module.is_empty() && !modules.contains(&module) {
+                modules.push(module);
+            }
+        }
+    }
+    modules.sort();
+    Some(modules)
+}
+
+// Each attribute is a byte holding the kind and the value's length, then the value big-endian
+fn location_attributes(bytes: &[u8]) -> Vec<(u8, u64)> {
+    let mut attributes = vec![];
+    let mut position = 0;
+    while let Some(byte) = bytes.get(position) {
+        let kind = byte >> 3;
+        if kind == ATTRIBUTE_END {
+            break;
+        }
+        let length = (byte & 0x7) as usize + 1;
+        let value = match bytes.get(position + 1..position + 1 + length) {
+            Some(value) => value.iter().fold(0, |value, byte| (value << 8) | *byte as u64),
+            None => break,
+        };
+        attributes.push((kind, value));
+        position += 1 + length;
+    }
+    attributes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::TempDir;
+
+    // A little-endian index with a location per resource, each given as (module, base, extension)
+    fn index(resources: &[(&str, &str, &str)]) -> Vec<u8> {
+        let mut strings = vec![0];
+        let mut string = |value: &str| {
+            let offset = strings.len() as u8;
+            strings.extend_from_slice(value.as_bytes());
+            strings.push(0);
+            offset
+        };
+        let mut offsets = vec![];
+        let mut locations = vec![];
+        for (module, base, extension) in resources {
+            offsets.extend_from_slice(&(locations.len() as u32).to_le_bytes());
+            locations.extend_from_slice(&[ATTRIBUTE_MODULE << 3, string(module), ATTRIBUTE_BASE << 3, string(base)]);
+            if !extension.is_empty() {
+                locations.extend_from_slice(&[ATTRIBUTE_EXTENSION << 3, string(extension)]);
+            }
+            locations.push(ATTRIBUTE_END);
+        }
+
+        let mut index = vec![];
+        for field in [MAGIC, 0x00010000, 0, resources.len() as u32, resources.len() as u32,
+                      locations.len() as u32, strings.len() as u32] {
+            index.extend_from_slice(&field.to_le_bytes());
+        }
+        index.extend(vec![0; resources.len() * 4]);
+        index.extend(offsets);
+        index.extend(locations);
+        index.extend(strings);
+        index
+    }
+
+    #[test]
+    fn test_parse_index() {
+        let index = index(&[
+            ("java.base", "module-info", "class"),
+            ("java.base", "Object", "class"),
+            ("javafx.controls", "module-info", "class"),
+            ("modules", "java.base", ""),
+            ("jdk.compiler", "module-info", "class"),
+        ]);
+        assert_eq!(parse_index(&index), Some(vec![
+            "java.base".to_string(),
+            "javafx.controls".to_string(),
+            "jdk.compiler".to_string(),
+        ]));
+        assert_eq!(index_size(&index), Some(index.len()));
+        assert_eq!(parse_index(&index[..index.len() - 4]), None);
+        assert_eq!(parse_index(b"PK\x03\x04 not an image at all"), None);
+    }
+
+    #[test]
+    fn test_image_modules() {
+        let dir = TempDir::new("jimage");
+        let path = dir.join("modules");
+        let mut image = index(&[("java.base", "module-info", "class")]);
+        // Resources follow the index
+        image.extend_from_slice(&[0xCA; 64]);
+        std::fs::write(&path, image).unwrap();
+        assert_eq!(image_modules(&path).unwrap(), vec!["java.base"]);
+        assert!(image_modules(&dir.join("missing")).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt;
+use std::path::Path;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+#[cfg(not(target_os = "windows"))]
+const JAVAC_EXECUTABLE: &str = "bin/javac";
+#[cfg(target_os = "windows")]
+const JAVAC_EXECUTABLE: &str = "bin\\javac.exe";
+
+// Aggregator module that every full Java SE runtime includes
+const JAVA_SE_MODULE: &str = "java.se";
+
+/// What a JVM home can be used for - a JDK can compile, a JRE can only run, and a runtime is a
+/// custom image made with jlink for a particular application
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum JvmType {
+    Jdk,
+    Jre,
+    Runtime,
+    #[default]
+    #[value(skip)]
+    Unknown,
+}
+
+impl JvmType {
+    /// Classifies a home from its files and the MODULES listed in its release file. jlink can
+    /// only build images from a JDK's jmods, so an image with a reduced module list and no jmods
+    /// of its own is a custom runtime.
+    pub fn classify(home: &Path, modules: &[String]) -> JvmType {
+        if !home.is_dir() {
+            return JvmType::Unknown;
+        }
+        // The javac launcher comes with the jdk.compiler module
+        if home.join(JAVAC_EXECUTABLE).is_file() {
+            return JvmType::Jdk;
+        }
+        let reduced = !modules.is_empty() && !modules.iter().any(|module| module == JAVA_SE_MODULE);
+        if reduced && !home.join("jmods").is_dir() {
+            return JvmType::Runtime;
+        }
+        JvmType::Jre
+    }
+}
+
+impl fmt::Display for JvmType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            JvmType::Jdk => "JDK",
+            JvmType::Jre => "JRE",
+            JvmType::Runtime => "runtime",
+            JvmType::Unknown => "unknown",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::fixture::TempDir;
+    use std::fs;
+
+    #[test]
+    fn test_classify() {
+        let root = TempDir::new("type");
+        let modules = |names: &[&str]| names.iter().map(|name| name.to_string()).collect::<Vec<String>>();
+        for home in ["jdk", "jre", "jre8", "runtime"] {
+            fs::create_dir_all(root.join(home).join("bin")).unwrap();
+            fs::write(root.join(home).join("bin/java"), "").unwrap();
+        }
+        fs::write(root.join("jdk/bin/javac"), "").unwrap();
+
+        let full = modules(&["java.base", "java.se", "jdk.compiler"]);
+        let reduced = modules(&["java.base", "java.logging"]);
+        assert_eq!(JvmType::classify(&root.join("jdk"), &full), JvmType::Jdk);
+        assert_eq!(JvmType::classify(&root.join("jre"), &full), JvmType::Jre);
+        assert_eq!(JvmType::classify(&root.join("jre8"), &[]), JvmType::Jre);
+        assert_eq!(JvmType::classify(&root.join("runtime"), &reduced), JvmType::Runtime);
+        fs::create_dir_all(root.join("runtime/jmods")).unwrap();
+        assert_eq!(JvmType::classify(&root.join("runtime"), &reduced), JvmType::Jre);
+        assert_eq!(JvmType::classify(&root.join("missing"), &full), JvmType::Unknown);
+    }
+}
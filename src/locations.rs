@@ -0,0 +1,108 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Default JVM locations by os-release ID. A trailing "*" means the matching entries of that
+// directory are JVM homes, otherwise the location is a JVM home itself.
+const DISTRO_LOCATIONS: [(&str, &[&str]); 18] = [
+    ("debian", &["/usr/lib/jvm/*"]),
+    ("ubuntu", &["/usr/lib/jvm/*"]),
+    ("rhel", &["/usr/lib/jvm/*"]),
+    ("centos", &["/usr/lib/jvm/*"]),
+    ("fedora", &["/usr/lib/jvm/*"]),
+    ("rocky", &["/usr/lib/jvm/*"]),
+    ("almalinux", &["/usr/lib/jvm/*"]),
+    ("amzn", &["/usr/lib/jvm/*"]),
+    ("ol", &["/usr/lib/jvm/*"]),
+    ("arch", &["/usr/lib/jvm/*"]),
+    ("manjaro", &["/usr/lib/jvm/*"]),
+    ("opensuse", &["/usr/lib64/jvm/*", "/usr/lib/jvm/*"]),
+    ("suse", &["/usr/lib64/jvm/*", "/usr/lib/jvm/*"]),
+    ("sles", &["/usr/lib64/jvm/*", "/usr/lib/jvm/*"]),
+    ("alpine", &["/usr/lib/jvm/*"]),
+    ("gentoo", &["/usr/lib64/openjdk-*", "/usr/lib/openjdk-*", "/opt/*"]),
+    ("void", &["/usr/lib/jvm/*"]),
+    ("nixos", &["/run/current-system/sw/lib/openjdk"]),
+];
+
+// Searched on every distribution - Oracle RPMs and the official container images
+const COMMON_LOCATIONS: [&str; 2] = ["/usr/java/*", "/opt/java/openjdk"];
+
+// Used when neither the ID nor anything in ID_LIKE is known
+const FALLBACK_LOCATIONS: [&str; 1] = ["/usr/lib/jvm/*"];
+
+/// Default locations for a distribution, trying its ID and then each ID_LIKE entry in turn
+pub fn default_locations(id: &str, like: &[String]) -> Vec<&'static str> {
+    let locations: &[&str] = std::iter::once(id)
+        .chain(like.iter().map(|like| like.as_str()))
+        .find_map(|id| DISTRO_LOCATIONS.iter().find(|(distro, _)| *distro == id))
+        .map(|(_, locations)| *locations)
+        .unwrap_or(&FALLBACK_LOCATIONS);
+    locations.iter().chain(COMMON_LOCATIONS.iter()).cloned().collect()
+}
+
+/// Candidate JVM homes for a location - a default that doesn't exist on this machine is not an error
+pub fn expand_location(location: &str) -> io::Result<Vec<PathBuf>> {
+    let path = Path::new(location);
+    let prefix = match path.file_name().and_then(|name| name.to_str()) {
+        Some(name) if name.ends_with('*') => name.trim_end_matches('*'),
+        // JVM home itself, resolved so links such as NixOS profiles are followed
+        _ => return not_found_as_empty(fs::canonicalize(path).map(|home| vec![home])),
+    };
+    let parent = match path.parent() {
+        Some(parent) => parent,
+        None => return Ok(vec![]),
+    };
+    not_found_as_empty(fs::read_dir(parent).map(|entries| entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(prefix)))
+        .collect()))
+}
+
+fn not_found_as_empty(result: io::Result<Vec<PathBuf>>) -> io::Result<Vec<PathBuf>> {
+    match result {
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::TempDir;
+
+    fn like(ids: &str) -> Vec<String> {
+        ids.split_whitespace().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_default_locations() {
+        assert_eq!(default_locations("ubuntu", &[]), vec!["/usr/lib/jvm/*", "/usr/java/*", "/opt/java/openjdk"]);
+        assert_eq!(default_locations("opensuse-tumbleweed", &like("opensuse suse")),
+                   vec!["/usr/lib64/jvm/*", "/usr/lib/jvm/*", "/usr/java/*", "/opt/java/openjdk"]);
+        assert_eq!(default_locations("gentoo", &[])[0], "/usr/lib64/openjdk-*");
+        assert_eq!(default_locations("linuxmint", &like("ubuntu debian"))[0], "/usr/lib/jvm/*");
+        assert_eq!(default_locations("nixos", &[])[0], "/run/current-system/sw/lib/openjdk");
+        assert_eq!(default_locations("", &[]), vec!["/usr/lib/jvm/*", "/usr/java/*", "/opt/java/openjdk"]);
+    }
+
+    #[test]
+    fn test_expand_location() {
+        let root = TempDir::new("locations");
+        fs::create_dir_all(root.join("openjdk-17")).unwrap();
+        fs::create_dir_all(root.join("openjdk-21")).unwrap();
+        fs::create_dir_all(root.join("other")).unwrap();
+
+        let mut homes = expand_location(root.join("openjdk-*").to_str().unwrap()).unwrap();
+        homes.sort();
+        assert_eq!(homes, vec![root.join("openjdk-17"), root.join("openjdk-21")]);
+        assert_eq!(expand_location(root.join("*").to_str().unwrap()).unwrap().len(), 3);
+        assert_eq!(expand_location(root.join("other").to_str().unwrap()).unwrap(),
+                   vec![fs::canonicalize(root.join("other")).unwrap()]);
+        assert_eq!(expand_location(root.join("missing").to_str().unwrap()).unwrap().len(), 0);
+        assert_eq!(expand_location(root.join("missing/*").to_str().unwrap()).unwrap().len(), 0);
+    }
+}
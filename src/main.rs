@@ -1,21 +1,74 @@
 use std::cmp::Ordering;
-#[cfg(target_os = "linux")]
 use std::collections::HashMap;
-use std::collections::HashSet;
+use std::collections::hash_map::Entry;
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+use std::ffi::CStr;
+use std::fmt;
 use std::fs;
 use std::fs::File;
 use std::hash::Hash;
 use std::io::BufReader;
-#[cfg(target_os = "windows")]
-use std::path::Path;
-#[cfg(any(target_os = "linux", target_os = "macos"))]
-use std::process::{Command, Stdio};
-use serde::{Serialize, Deserialize};
-use clap::Parser;
+use std::path::{Path, PathBuf};
+use serde::{Serialize, Serializer, Deserialize};
+use clap::{Parser, ValueEnum};
 use java_properties::read;
+#[cfg(target_os = "linux")]
+use locations::{default_locations, expand_location};
 #[cfg(target_os = "macos")]
 use plist::Value;
 
+use active::{java_home, path_java_home};
+#[cfg(target_os = "linux")]
+use active::executable_home;
+#[cfg(target_os = "linux")]
+use alternatives::{current_alternative, java_alternatives};
+#[cfg(target_os = "linux")]
+use packages::owning_packages;
+use arch::Architecture;
+use candidate::Candidate;
+#[cfg(target_os = "linux")]
+use dirname::parse_dir_name;
+use jimage::image_modules;
+use jvm_type::JvmType;
+use pattern::NamePattern;
+use release::{Release, ReleaseFilter};
+use report::ScanReport;
+use selector::{is_lts, VersionSelector};
+#[cfg(unix)]
+use sources::{jetbrains, linuxbrew, nix, user_candidates};
+#[cfg(target_os = "windows")]
+use toolchains::user_toolchains;
+use tools::home_tools;
+use vendor::Vendor;
+use version::JavaVersion;
+
+mod active;
+#[cfg(target_os = "linux")]
+mod alternatives;
+mod arch;
+mod candidate;
+#[cfg(target_os = "linux")]
+mod dirname;
+#[cfg(test)]
+mod fixture;
+mod jimage;
+mod jvm_type;
+#[cfg(target_os = "linux")]
+mod locations;
+mod pattern;
+#[cfg(target_os = "linux")]
+mod packages;
+mod range;
+mod release;
+mod report;
+mod selector;
+#[cfg(unix)]
+mod sources;
+mod toolchains;
+mod tools;
+mod vendor;
+mod version;
+
 #[cfg(target_os = "windows")]
 extern crate winreg;
 #[cfg(target_os = "windows")]
@@ -28,15 +81,35 @@ use winreg::enums::HKEY_LOCAL_MACHINE;
 #[derive(Parser, Debug)]
 #[clap(author, about, long_about = None)]
 struct Args {
-    /// JVM Name to filter on
+    /// JVM Name to filter on - case-insensitive substring, glob (e.g. "*temurin*") or regex (e.g. "re:^Eclipse")
     #[clap(short, long)]
     name: Option<String>,
 
-    /// Architecture to filter on (e.g. x86_64, aarch64, amd64)
+    /// Architecture to filter on (e.g. x86_64, aarch64, amd64, arm64, x86, arm, ppc64le, s390x, riscv64)
     #[clap(short, long)]
     arch: Option<String>,
 
-    /// Version to filter on (e.g. 1.8, 11, 17, etc)
+    /// Vendor to filter on (e.g. temurin, zulu, corretto, liberica, graalvm, oracle)
+    #[clap(long)]
+    vendor: Option<String>,
+
+    /// Release file value to filter on, as KEY=PATTERN (e.g. LIBC=musl, JVM_VARIANT=openj9, IMPLEMENTOR="*Adoptium*") (repeatable)
+    #[clap(long, value_name = "KEY=PATTERN")]
+    release: Vec<String>,
+
+    /// Kind of JVM to filter on - a JDK, a JRE, or a custom runtime image made with jlink
+    #[clap(long = "type", value_enum, value_name = "TYPE")]
+    jvm_type: Option<JvmType>,
+
+    /// Tool the JVM's bin directory must contain (e.g. jlink, jpackage, native-image, jfr, jshell) (repeatable)
+    #[clap(long, value_name = "NAME")]
+    with_tool: Vec<String>,
+
+    /// Module the JVM must include (e.g. javafx.controls, java.desktop, jdk.incubator.vector) (repeatable)
+    #[clap(long, value_name = "MODULE")]
+    with_module: Vec<String>,
+
+    /// Version, range or selector to filter on (e.g. 1.8, 17, 11+, ">=11 <17", 11..17, "[11,17)", lts, lts+, latest, oldest, "latest-patch-of 17")
     #[clap(short, long)]
     version: Option<String>,
 
@@ -44,10 +117,35 @@ struct Args {
     #[clap(short, long)]
     detailed: bool,
 
+    /// Print out full details of every JVM found as JSON
+    #[clap(long)]
+    json: bool,
+
+    /// Order to list JVMs in - by version, or by update-alternatives priority
+    #[clap(long, value_enum, default_value_t = SortOrder::Version)]
+    sort: SortOrder,
+
     /// Return error code if no JVM found
     #[clap(short, long)]
     fail: bool,
 
+    /// Include early access and other pre-release builds
+    #[clap(long)]
+    include_prerelease: bool,
+
+    /// Include runtimes bundled with an IDE, which are otherwise only included when picked out by
+    /// vendor or name
+    #[clap(long)]
+    include_ide_bundled: bool,
+
+    /// Also search a source that isn't searched by default (repeatable)
+    #[clap(long, value_enum)]
+    include: Vec<OptionalSource>,
+
+    /// Print diagnostics about locations and entries skipped while searching
+    #[clap(long)]
+    verbose: bool,
+
     /// Add location
     #[clap(short = 'r', long)]
     register_location: Option<String>,
@@ -61,33 +159,88 @@ struct Args {
     display_locations: bool
 }
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+enum SortOrder {
+    Version,
+    Priority,
+}
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize)]
 struct Jvm {
     version: String,
     name: String,
-    architecture: String,
-    path: String
+    #[serde(serialize_with = "serialize_display")]
+    architecture: Architecture,
+    path: String,
+    #[serde(serialize_with = "serialize_display")]
+    vendor: Vendor,
+    tags: Vec<String>,
+    active: bool,
+    alternatives_priority: Option<i64>,
+    system_default: bool,
+    package: Option<String>,
+    package_version: Option<String>,
+    release: Release,
+    jvm_type: JvmType,
+    tools: Vec<String>
 }
 
 #[derive(Clone)]
 struct OperatingSystem {
     name: String,
-    architecture: String
+    like: Vec<String>,
+    architecture: Architecture
 }
 
+/// Sources that are only searched when asked for, as they are slower or less commonly wanted
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+enum OptionalSource {
+    /// Nix store and ~/.nix-profile
+    Nix,
+    /// Homebrew on Linux
+    Linuxbrew,
+    /// Runtimes bundled with JetBrains IDEs
+    Jetbrains,
+}
+
+// Tag for runtimes bundled with an IDE, which are there for the IDE rather than for general use
+const IDE_BUNDLED: &str = "ide-bundled";
+
 #[derive(Serialize, Deserialize)]
 struct Config {
-    paths: Vec<String>
+    paths: Vec<String>,
+    #[serde(default)]
+    include_prerelease: bool,
+    #[serde(default)]
+    include: Vec<OptionalSource>
 }
 
 impl Default for Config {
     fn default() -> Self {
         Config {
-            paths: vec![]
+            paths: vec![],
+            include_prerelease: false,
+            include: vec![]
         }
     }
 }
 
+// Structured output uses the same canonical names as the detailed output
+fn serialize_display<T: fmt::Display, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+impl Jvm {
+    fn is_prerelease(&self) -> bool {
+        JavaVersion::parse(&self.version).is_some_and(|version| version.is_prerelease())
+    }
+
+    fn is_ide_bundled(&self) -> bool {
+        self.tags.iter().any(|tag| tag == IDE_BUNDLED)
+    }
+}
+
 fn main() {
     let args = Args::parse();
     let mut cfg: Config = confy::load("javalocate").unwrap();
@@ -122,36 +275,83 @@ fn main() {
         std::process::exit(exitcode::OK);
     }
 
+    let version_selector = args.version.as_ref().map(|version| {
+        VersionSelector::parse(version).unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            std::process::exit(exitcode::USAGE);
+        })
+    });
+
+    let name_pattern = args.name.as_ref().map(|name| {
+        NamePattern::parse(name).unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            std::process::exit(exitcode::USAGE);
+        })
+    });
+
+    let architecture = args.arch.as_ref().map(|arch| Architecture::parse(arch));
+    let vendor = args.vendor.as_ref().map(|vendor| Vendor::parse(vendor));
+    let release_filters: Vec<ReleaseFilter> = args.release.iter().map(|filter| {
+        ReleaseFilter::parse(filter).unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            std::process::exit(exitcode::USAGE);
+        })
+    }).collect();
+
     // Fetch default java architecture based on kernel
     let operating_system = get_operating_system();
 
     // Build and filter JVMs
-    let jvms: Vec<Jvm> = collate_jvms(&operating_system, &cfg)
+    let include_prerelease = args.include_prerelease || cfg.include_prerelease;
+    // IDE-bundled runtimes are only considered when asked for explicitly, whatever the output
+    let include_ide_bundled = args.include_ide_bundled || name_pattern.is_some() || vendor == Some(Vendor::JetBrains);
+    cfg.include.extend(&args.include);
+    let mut report = ScanReport::default();
+    let jvms: Vec<Jvm> = collate_jvms(&operating_system, &cfg, &mut report)
         .into_iter()
-        .filter(|tmp| filter_arch(&args.arch, tmp))
-        .filter(|tmp| filter_ver(&args.version, tmp))
-        .filter(|tmp| filter_name(&args.name, tmp))
+        .map(inspect_home)
+        .filter(|tmp| include_prerelease || !tmp.is_prerelease())
+        .filter(|tmp| filter_ide_bundled(include_ide_bundled, tmp))
+        .filter(|tmp| filter_arch(&architecture, tmp))
+        .filter(|tmp| filter_ver(&version_selector, tmp))
+        .filter(|tmp| filter_name(&name_pattern, tmp))
+        .filter(|tmp| filter_vendor(&vendor, tmp))
+        .filter(|tmp| filter_type(&args.jvm_type, tmp))
+        .filter(|tmp| args.with_tool.iter().all(|tool| tmp.tools.contains(tool)))
+        .filter(|tmp| filter_modules(&args.with_module, tmp))
+        .filter(|tmp| release_filters.iter().all(|filter| filter.matches(&tmp.release)))
         .collect();
+    let mut jvms = select_ver(&version_selector, jvms);
+    if args.sort == SortOrder::Priority {
+        sort_by_priority(&mut jvms);
+    }
+    // Looking packages up means reading the package database, so it's only done for the JVMs
+    // being shown in full
+    #[cfg(target_os = "linux")]
+    if args.detailed || args.json {
+        collate_packages(&mut jvms, &mut report);
+    }
+    for message in report.messages(args.verbose) {
+        eprintln!("{}", message);
+    }
 
     // If empty decide on response based on fail param
     if jvms.is_empty() {
         if args.fail {
             eprintln!("Couldn't find a JVM to use.");
             std::process::exit(exitcode::CONFIG);
-        } else {
+        } else if !args.json {
             std::process::exit(exitcode::OK);
         }
     }
 
     // If JVMs found, display
-    if args.detailed {
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&jvms).unwrap());
+    }
+    else if args.detailed {
         for jvm in &jvms {
-            println!("{} ({}) \"{}\" - {}",
-                     jvm.version,
-                     jvm.architecture,
-                     jvm.name,
-                     jvm.path
-            );
+            println!("{}", format_detailed(jvm));
         }
     }
     else {
@@ -160,300 +360,366 @@ fn main() {
 }
 
 
+fn format_detailed(jvm: &Jvm) -> String {
+    let mut platform = jvm.architecture.to_string();
+    if jvm.vendor != Vendor::Unknown {
+        platform.push_str(format!(", {}", jvm.vendor).as_str());
+    }
+    if jvm.jvm_type != JvmType::Unknown {
+        platform.push_str(format!(", {}", jvm.jvm_type).as_str());
+    }
+    let mut detail = format!("{} ({}) \"{}\" - {}",
+                             jvm.version,
+                             platform,
+                             jvm.name,
+                             jvm.path
+    );
+    if jvm.is_prerelease() {
+        detail.push_str(" [pre-release]");
+    }
+    if jvm.active {
+        detail.push_str(" [active]");
+    }
+    if jvm.system_default {
+        detail.push_str(" [system default]");
+    }
+    if let Some(priority) = jvm.alternatives_priority {
+        detail.push_str(format!(" [priority {}]", priority).as_str());
+    }
+    match (&jvm.package, &jvm.package_version) {
+        (Some(package), Some(version)) if !version.is_empty() => {
+            detail.push_str(format!(" [package {} {}]", package, version).as_str());
+        }
+        (Some(package), _) => detail.push_str(format!(" [package {}]", package).as_str()),
+        _ => {}
+    }
+    for tag in &jvm.tags {
+        detail.push_str(format!(" [{}]", tag).as_str());
+    }
+    // Tools and release file metadata follow on their own lines, the metadata as it appears in the file
+    if !jvm.tools.is_empty() {
+        detail.push_str(format!("\n    tools: {}", jvm.tools.join(" ")).as_str());
+    }
+    for (key, value) in jvm.release.entries() {
+        detail.push_str(format!("\n    {}=\"{}\"", key, value).as_str());
+    }
+    detail
+}
+
 #[cfg(any(target_os = "linux", target_os = "macos"))]
 fn get_operating_system() -> OperatingSystem {
-    let output = Command::new("uname")
-        .arg("-ps")
-        .stdout(Stdio::piped())
-        .output().unwrap();
-
-    let stdout = String::from_utf8(output.stdout).unwrap();
-    let parts: Vec<String> =
-        stdout.split(" ").map(|s| s.to_string()).collect();
-
-    let os = trim_string(parts.get(0).unwrap().as_str());
-    let arch = trim_string(parts.get(1).unwrap().as_str());
-
-    let default_architecture =
-        if os.eq_ignore_ascii_case("Darwin") {
-            if arch.eq_ignore_ascii_case("arm") {
-                "aarch64".to_string()
-            } else {
-                "x86_64".to_string()
-            }
-        } else if os.eq_ignore_ascii_case("Linux") {
-            if arch.eq_ignore_ascii_case("x86_64") {
-                "x86_64".to_string()
-            } else if arch.eq_ignore_ascii_case("i386") {
-                "x86".to_string()
-            } else if arch.eq_ignore_ascii_case("i586") {
-                "x86".to_string()
-            } else if arch.eq_ignore_ascii_case("i686") {
-                "x86".to_string()
-            } else if arch.eq_ignore_ascii_case("aarch64") {
-                "aarch64".to_string()
-            } else if arch.eq_ignore_ascii_case("arm64") {
-                "arm64".to_string()
-            } else {
-                eprintln!("{} architecture is unknown on Linux", arch);
-                std::process::exit(exitcode::UNAVAILABLE);
-            }
-        } else {
-            eprintln!("Running on non-supported operation system");
-            std::process::exit(exitcode::UNAVAILABLE);
-        };
+    // Ask the kernel, falling back to what this binary was built for
+    let (os, machine) = uname().unwrap_or_else(|| {
+        (std::env::consts::OS.to_string(), std::env::consts::ARCH.to_string())
+    });
+
+    // Unrecognised machines are kept as-is, so only the architecture preference is affected
+    let default_architecture = Architecture::parse(&machine);
 
     let mut name = String::new();
+    let mut like = vec![];
     if os.eq_ignore_ascii_case("Linux") {
         // Attempt to load the Release file into HashMap
-        let release_file = File::open("/etc/os-release");
-        let release_file = match release_file {
-            Ok(release_file) => release_file,
-            Err(_error) => std::process::exit(exitcode::UNAVAILABLE),
-        };
-        let properties = read(BufReader::new(release_file)).unwrap();
-        name.push_str(properties.get("ID").unwrap_or(&"".to_string()).replace("\"", "").as_str());
+        let properties = File::open("/etc/os-release")
+            .ok()
+            .and_then(|release_file| read(BufReader::new(release_file)).ok());
+        if let Some(properties) = properties {
+            name.push_str(release_value(&properties, "ID").as_str());
+            // Distributions derived from others list them - e.g. ID_LIKE="rhel centos fedora"
+            like = release_value(&properties, "ID_LIKE")
+                .split_whitespace()
+                .map(|id| id.to_string())
+                .collect();
+        }
     } else if os.eq_ignore_ascii_case("Darwin") {
         name.push_str("macOS");
+    } else {
+        name.push_str(os.as_str());
     }
 
     return OperatingSystem {
         name,
+        like,
         architecture: default_architecture
     }
 }
 
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn uname() -> Option<(String, String)> {
+    let mut system: libc::utsname = unsafe { std::mem::zeroed() };
+    if unsafe { libc::uname(&mut system) } != 0 {
+        return None;
+    }
+    let field = |value: &[libc::c_char]| {
+        unsafe { CStr::from_ptr(value.as_ptr()) }.to_string_lossy().into_owned()
+    };
+    Some((field(&system.sysname), field(&system.machine)))
+}
+
 #[cfg(target_os = "windows")]
 fn get_operating_system() -> OperatingSystem {
     let current_version = RegKey::predef(HKEY_LOCAL_MACHINE).open_subkey("SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion").unwrap();
     let name: String = current_version.get_value("ProductName").unwrap();
 
-    let environment = RegKey::predef(HKEY_LOCAL_MACHINE).open_subkey("SYSTEM\\CurrentControlSet\\Control\\Session Manager\\Environment").unwrap();
-    let arch: String = environment.get_value("PROCESSOR_ARCHITECTURE").unwrap();
-    let default_architecture =
-        if arch.eq_ignore_ascii_case("amd64") {
-            "x86_64".to_string()
-        } else if arch.eq_ignore_ascii_case("x86") {
-            "x86".to_string()
-        } else if arch.eq_ignore_ascii_case("arm64") {
-            "arm64".to_string()
-        } else {
-            eprintln!("Unknown processor architecture");
-            std::process::exit(exitcode::UNAVAILABLE);
-        };
+    // Fall back to what this binary was built for if the processor can't be read
+    let arch: String = RegKey::predef(HKEY_LOCAL_MACHINE)
+        .open_subkey("SYSTEM\\CurrentControlSet\\Control\\Session Manager\\Environment")
+        .and_then(|environment| environment.get_value("PROCESSOR_ARCHITECTURE"))
+        .unwrap_or_else(|_| std::env::consts::ARCH.to_string());
+    let default_architecture = Architecture::parse(&arch);
 
     return OperatingSystem {
         name,
+        like: vec![],
         architecture: default_architecture
     }
 }
 
-#[cfg(any(target_os = "linux", target_os = "macos"))]
-fn trim_string(value: &str) -> &str {
-    value.strip_suffix("\r\n")
-        .or(value.strip_suffix("\n"))
-        .unwrap_or(value)
-}
-
 #[cfg(target_os = "linux")]
-fn collate_jvms(os: &OperatingSystem, cfg: &Config) -> Vec<Jvm> {
-    let mut jvms = HashSet::new();
-    let dir_lookup = HashMap::from(
-        [("ubuntu".to_string(), "/usr/lib/jvm".to_string()),
-            ("debian".to_string(), "/usr/lib/jvm".to_string()),
-            ("rhel".to_string(), "/usr/lib/jvm".to_string()),
-            ("centos".to_string(), "/usr/lib/jvm".to_string()),
-            ("fedora".to_string(), "/usr/lib/jvm".to_string())]);
-
-    let path = dir_lookup.get(os.name.as_str());
-    if path.is_none() && cfg.paths.is_empty() {
-        eprintln!("Default JVM path is unknown on {} Linux", os.name);
-        std::process::exit(exitcode::UNAVAILABLE);
-    }
-    let mut paths = cfg.paths.to_vec();
-    paths.push(path.unwrap().to_string());
+fn collate_jvms(os: &OperatingSystem, cfg: &Config, report: &mut ScanReport) -> Vec<Jvm> {
+    let mut jvms = HashMap::new();
 
-    for path in paths {
-        for path in fs::read_dir(path).unwrap() {
-            let path = path.unwrap().path();
-            let metadata = fs::metadata(&path).unwrap();
-            let link = fs::read_link(&path);
-
-            if metadata.is_dir() && link.is_err() {
-                // Attempt to use release file, if not, attempt to build from folder name
-                let release_file = File::open(path.join("release"));
-                if release_file.is_ok() {
-                    // Collate required information
-                    let properties = read(BufReader::new(release_file.unwrap())).unwrap();
-                    let version = properties.get("JAVA_VERSION").unwrap_or(&"".to_string()).replace("\"", "");
-                    let architecture = properties.get("OS_ARCH").unwrap_or(&"".to_string()).replace("\"", "");
-                    let name = path.file_name().unwrap().to_str().unwrap().to_string();
-
-                    // Build JVM Struct
-                    let tmp_jvm = Jvm {
-                        version,
-                        architecture,
-                        name,
-                        path: path.to_str().unwrap().to_string(),
-                    };
-                    jvms.insert(tmp_jvm);
-                } else {
-                    let file_name = path.file_name().unwrap().to_str().unwrap();
-                    let parts: Vec<String> = file_name.split("-").map(|s| s.to_string()).collect();
-                    // Assuming four part or more form - e.g. "java-8-openjdk-amd64"
-                    if parts.len() < 3 || !parts.get(1).unwrap().to_string().eq("java") {
-                        continue;
-                    }
-
-                    let version = parts.get(1).unwrap().to_string();
-                    let mut architecture = parts.get(3).unwrap().to_string();
-                    architecture = architecture.replace("amd64", "x86_64");
-                    architecture = architecture.replace("i386", "x86");
-                    let name = file_name.to_string();
-
-                    // Build JVM Struct
-                    let tmp_jvm = Jvm {
-                        version,
-                        architecture,
-                        name,
-                        path: path.to_str().unwrap().to_string(),
-                    };
-                    jvms.insert(tmp_jvm);
-                }
-            }
+    // Custom locations, then the defaults for the distribution
+    let mut paths: Vec<PathBuf> = vec![];
+    for path in &cfg.paths {
+        paths.append(&mut read_location(Path::new(path), report));
+    }
+    for location in default_locations(&os.name, &os.like) {
+        match expand_location(location) {
+            Ok(mut homes) => paths.append(&mut homes),
+            Err(error) => report.location(location, error),
         }
     }
-    let mut return_vec: Vec<Jvm> = jvms.into_iter().collect();
+
+    collate_location_jvms(&mut jvms, paths, report);
+    collate_user_jvms(&mut jvms, cfg, report);
+    collate_active_jvms(&mut jvms, report);
+    collate_alternatives(&mut jvms, report);
+
+    let mut return_vec: Vec<Jvm> = jvms.into_values().collect();
     return_vec.sort_by(|a, b| compare_boosting_architecture(a, b, &os.architecture));
     return return_vec;
 }
 
-#[cfg(target_os = "macos")]
-fn collate_jvms(os: &OperatingSystem, cfg: &Config) -> Vec<Jvm> {
-    assert!(os.name.contains("macOS"));
-    let mut jvms = HashSet::new();
-    let mut paths = cfg.paths.to_vec();
-    paths.push("/Library/Java/JavaVirtualMachines".to_string());
+// JVM homes among the entries of the locations searched
+#[cfg(target_os = "linux")]
+fn collate_location_jvms(jvms: &mut HashMap<PathBuf, Jvm>, paths: Vec<PathBuf>, report: &mut ScanReport) {
     for path in paths {
-        for path in fs::read_dir(path).unwrap() {
-            let path = path.unwrap().path();
-            let metadata = fs::metadata(&path).unwrap();
-
-            if metadata.is_dir() {
-                // Attempt to load the Info PList
-                let info =
-                    Value::from_file(path.join("Contents/Info.plist"));
-
-                let info = match info {
-                    Ok(info) => info,
-                    Err(_error) => continue,
-                };
-                let name = info
-                    .as_dictionary()
-                    .and_then(|dict| dict.get("CFBundleName"))
-                    .and_then(|info_string| info_string.as_string());
-                let name = name.unwrap_or(&"".to_string()).replace("\"", "");
-
-                // Attempt to load the Release file into HashMap
-                let release_file = File::open(path.join("Contents/Home/release"));
-                let release_file = match release_file {
-                    Ok(release_file) => release_file,
-                    Err(_error) => continue,
-                };
+        let metadata = match fs::metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(error) => {
+                report.entry(&path, error);
+                continue;
+            }
+        };
+        let link = fs::read_link(&path);
 
+        if metadata.is_dir() && link.is_err() {
+            // Attempt to use release file, if not, attempt to build from folder name
+            let release = read_release(&path.join("release"), report);
+            if let Some(properties) = release {
                 // Collate required information
-                let properties = read(BufReader::new(release_file)).unwrap();
-                let version = properties.get("JAVA_VERSION").unwrap_or(&"".to_string()).replace("\"", "");
-                let architecture = properties.get("OS_ARCH").unwrap_or(&"".to_string()).replace("\"", "");
+                let version = release_value(&properties, "JAVA_VERSION");
+                let architecture = Architecture::parse(&release_value(&properties, "OS_ARCH"));
+                let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
 
                 // Build JVM Struct
                 let tmp_jvm = Jvm {
                     version,
                     architecture,
                     name,
-                    path: path.join("Contents/Home").to_str().unwrap().to_string(),
+                    path: path.to_string_lossy().to_string(),
+                    vendor: release_vendor(&properties),
+                    release: Release::from_properties(&properties),
+                    ..Default::default()
+                };
+                insert_jvm(jvms, tmp_jvm);
+            } else if path.join("bin/java").is_file() || path.join("jre/bin/java").is_file() {
+                // Older JVMs have no release file, so fall back to what the directory name says
+                let file_name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+                let Some(parsed) = parse_dir_name(&file_name) else {
+                    continue;
                 };
-                jvms.insert(tmp_jvm);
+
+                // Build JVM Struct
+                let tmp_jvm = Jvm {
+                    version: parsed.version,
+                    architecture: parsed.architecture,
+                    name: file_name,
+                    path: path.to_string_lossy().to_string(),
+                    vendor: parsed.vendor,
+                    ..Default::default()
+                };
+                insert_jvm(jvms, tmp_jvm);
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn collate_jvms(os: &OperatingSystem, cfg: &Config, report: &mut ScanReport) -> Vec<Jvm> {
+    assert!(os.name.contains("macOS"));
+    let mut jvms = HashMap::new();
+    let mut paths: Vec<PathBuf> = vec![];
+    for path in &cfg.paths {
+        paths.append(&mut read_location(Path::new(path), report));
+    }
+    // Default location only exists once a JVM has been installed
+    let default_path = Path::new("/Library/Java/JavaVirtualMachines");
+    if default_path.exists() {
+        paths.append(&mut read_location(default_path, report));
+    }
+
+    for path in paths {
+        let metadata = match fs::metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(error) => {
+                report.entry(&path, error);
+                continue;
             }
+        };
+
+        if metadata.is_dir() {
+            // Attempt to load the Info PList
+            let info =
+                Value::from_file(path.join("Contents/Info.plist"));
+
+            let info = match info {
+                Ok(info) => info,
+                Err(_error) => continue,
+            };
+            let name = info
+                .as_dictionary()
+                .and_then(|dict| dict.get("CFBundleName"))
+                .and_then(|info_string| info_string.as_string());
+            let name = name.unwrap_or(&"".to_string()).replace("\"", "");
+
+            // Attempt to load the Release file into HashMap
+            let properties = match read_release(&path.join("Contents/Home/release"), report) {
+                Some(properties) => properties,
+                None => continue,
+            };
+
+            // Collate required information
+            let version = release_value(&properties, "JAVA_VERSION");
+            let architecture = Architecture::parse(&release_value(&properties, "OS_ARCH"));
+
+            // Build JVM Struct
+            let tmp_jvm = Jvm {
+                version,
+                architecture,
+                name,
+                path: path.join("Contents/Home").to_string_lossy().to_string(),
+                vendor: release_vendor(&properties),
+                release: Release::from_properties(&properties),
+                ..Default::default()
+            };
+            insert_jvm(&mut jvms, tmp_jvm);
         }
     }
-    let mut return_vec: Vec<Jvm> = jvms.into_iter().collect();
+    collate_user_jvms(&mut jvms, cfg, report);
+    collate_active_jvms(&mut jvms, report);
+
+    let mut return_vec: Vec<Jvm> = jvms.into_values().collect();
     return_vec.sort_by(|a, b| compare_boosting_architecture(a, b, &os.architecture));
     return return_vec;
 }
 
 #[cfg(target_os = "windows")]
-fn collate_jvms(os: &OperatingSystem, cfg: &Config) -> Vec<Jvm> {
+fn collate_jvms(os: &OperatingSystem, cfg: &Config, report: &mut ScanReport) -> Vec<Jvm> {
     assert!(os.name.contains("Windows"));
-    let mut jvms = HashSet::new();
+    let mut jvms = HashMap::new();
 
     // Loop round software keys in the registry
-    let system = RegKey::predef(HKEY_LOCAL_MACHINE).open_subkey("SOFTWARE").unwrap();
-    for name in system.enum_keys().map(|x| x.unwrap()) {
-        let software: String = name.clone();
+    match RegKey::predef(HKEY_LOCAL_MACHINE).open_subkey("SOFTWARE") {
+        Ok(system) => collate_registry_jvms(&system, &mut jvms, report),
+        Err(error) => report.location("HKEY_LOCAL_MACHINE\\SOFTWARE", error),
+    }
+    // Read from Custom JVM Location Paths
+    for path in &cfg.paths {
+        for jvm_path in read_location(Path::new(path), report) {
+            let metadata = match fs::metadata(&jvm_path) {
+                Ok(metadata) => metadata,
+                Err(error) => {
+                    report.entry(&jvm_path, error);
+                    continue;
+                }
+            };
+
+            if metadata.is_dir() {
+                if let Some(jvm) = process_release_file(&jvm_path.to_string_lossy().to_string(), report) {
+                    insert_jvm(&mut jvms, jvm);
+                }
+            }
+        }
+    }
+    // JDKs listed in the user's Maven toolchains
+    for candidate in user_toolchains(report) {
+        if let Some(jvm) = candidate_jvm(candidate, report) {
+            insert_jvm(&mut jvms, jvm);
+        }
+    }
+    collate_active_jvms(&mut jvms, report);
+
+    let mut return_vec: Vec<Jvm> = jvms.into_values().collect();
+    return_vec.sort_by(|a, b| compare_boosting_architecture(a, b, &os.architecture));
+    return return_vec;
+}
+
+#[cfg(target_os = "windows")]
+fn collate_registry_jvms(system: &RegKey, jvms: &mut HashMap<PathBuf, Jvm>, report: &mut ScanReport) {
+    for software in system.enum_keys().flatten() {
+        // Keys that can't be opened can't hold a readable JDK entry either
+        let software_key = match system.open_subkey(&software) {
+            Ok(software_key) => software_key,
+            Err(_error) => continue,
+        };
         // Find software with JDK key
-        for jdk in system.open_subkey(name).unwrap().enum_keys()
-                            .map(|x| x.unwrap())
-                            .filter(|x| x.starts_with("JDK")) {
+        for jdk in software_key.enum_keys().flatten().filter(|x| x.starts_with("JDK")) {
+            let jdk_key = match software_key.open_subkey(&jdk) {
+                Ok(jdk_key) => jdk_key,
+                Err(error) => {
+                    report.entry(format!("HKEY_LOCAL_MACHINE\\SOFTWARE\\{}\\{}", software, jdk), error);
+                    continue;
+                }
+            };
             // Next key should be JVM
-            for jvm in system.open_subkey(format!("{}\\{}", software, jdk)).unwrap().enum_keys().map(|x| x.unwrap()) {
+            for jvm in jdk_key.enum_keys().flatten() {
                 let mut jvm_path = String::new();
                 // Old style JavaSoftware entry
-                let java_home: Result<String, _> = system.open_subkey(format!("{}\\{}\\{}", software, jdk, jvm)).unwrap().get_value("JavaHome");
-                if java_home.is_ok() {
-                    jvm_path = java_home.unwrap();
+                let java_home: Result<String, _> = jdk_key.open_subkey(&jvm)
+                    .and_then(|key| key.get_value("JavaHome"));
+                if let Ok(java_home) = java_home {
+                    jvm_path = java_home;
                 }
                 // Per JVM Entry - check for Hotspot or OpenJ9 entry
-                let hotspot_path: Result<RegKey, _> = system.open_subkey(format!("{}\\{}\\{}\\hotspot\\MSI", software, jdk, jvm));
-                if hotspot_path.is_ok() {
-                    jvm_path = hotspot_path.unwrap().get_value("Path").unwrap();
-                }
-                let openj9_path: Result<RegKey, _> = system.open_subkey(format!("{}\\{}\\{}\\openj9\\MSI", software, jdk, jvm));
-                if openj9_path.is_ok() {
-                    jvm_path = openj9_path.unwrap().get_value("Path").unwrap();
+                for variant in ["hotspot", "openj9"] {
+                    let msi_path: Result<String, _> = jdk_key.open_subkey(format!("{}\\{}\\MSI", jvm, variant))
+                        .and_then(|key| key.get_value("Path"));
+                    if let Ok(msi_path) = msi_path {
+                        jvm_path = msi_path;
+                    }
                 }
                 jvm_path = jvm_path.strip_suffix("\\").unwrap_or(jvm_path.as_str()).to_string();
-
-                let path = Path::new(jvm_path.as_str()).join("release");
-                let release_file = File::open(path);
-                if release_file.is_ok() {
-                    jvms.insert(process_release_file(&jvm_path, release_file.unwrap()));
-                }
-            }
-        }
-    }
-    // Read from Custom JVM Location Paths
-    if !cfg.paths.is_empty() {
-        for path in &cfg.paths {
-            for path in fs::read_dir(path).unwrap() {
-                let jvm_path = path.unwrap().path();
-                let metadata = fs::metadata(&jvm_path).unwrap();
-
-                if metadata.is_dir() {
-                    let path = Path::new(jvm_path.to_str().unwrap()).join("release");
-                    let release_file = File::open(&path);
-                    if release_file.is_ok() {
-                        jvms.insert(process_release_file(&jvm_path.to_str().unwrap().to_string(), release_file.unwrap()));
-                    }
+                if jvm_path.is_empty() {
+                    continue;
                 }
 
+                if let Some(jvm) = process_release_file(&jvm_path, report) {
+                    insert_jvm(jvms, jvm);
+                }
             }
         }
     }
-    let mut return_vec: Vec<Jvm> = jvms.into_iter().collect();
-    return_vec.sort_by(|a, b| compare_boosting_architecture(a, b, &os.architecture));
-    return return_vec;
 }
 
 #[cfg(target_os = "windows")]
-fn process_release_file(jvm_path: &String, release_file: File) -> Jvm {
+fn process_release_file(jvm_path: &String, report: &mut ScanReport) -> Option<Jvm> {
     // Collate required information
-    let properties = read(BufReader::new(release_file)).unwrap();
-    let version = properties.get("JAVA_VERSION").unwrap_or(&"".to_string()).replace("\"", "");
-    let mut architecture = properties.get("OS_ARCH").unwrap_or(&"".to_string()).replace("\"", "");
-    architecture = architecture.replace("amd64", "x86_64");
-    architecture = architecture.replace("i386", "x86");
-    let implementor = properties.get("IMPLEMENTOR").unwrap_or(&"".to_string()).replace("\"", "");
+    let properties = read_release(&Path::new(jvm_path).join("release"), report)?;
+    let version = release_value(&properties, "JAVA_VERSION");
+    let architecture = Architecture::parse(&release_value(&properties, "OS_ARCH"));
+    let implementor = release_value(&properties, "IMPLEMENTOR");
     let name = format!("{} - {}", implementor, version);
 
     // Build JVM Struct
@@ -462,126 +728,343 @@ fn process_release_file(jvm_path: &String, release_file: File) -> Jvm {
         architecture,
         name,
         path: jvm_path.to_string(),
+        vendor: release_vendor(&properties),
+        release: Release::from_properties(&properties),
+        ..Default::default()
     };
-    tmp_jvm
+    Some(tmp_jvm)
 }
 
-fn compare_boosting_architecture(a: &Jvm, b: &Jvm, default_arch: &String) -> Ordering {
-    let version_test = compare_version_values(&b.version, &a.version);
-    if version_test == Ordering::Equal {
-        if b.architecture != default_arch.as_str() && a.architecture == default_arch.as_str() {
-            return Ordering::Less;
+// Installs made by per-user tools such as SDKMAN!, and any optional sources asked for
+#[cfg(unix)]
+fn collate_user_jvms(jvms: &mut HashMap<PathBuf, Jvm>, cfg: &Config, report: &mut ScanReport) {
+    let mut candidates = user_candidates(report);
+    if cfg.include.contains(&OptionalSource::Nix) {
+        candidates.append(&mut nix(report));
+    }
+    if cfg.include.contains(&OptionalSource::Linuxbrew) {
+        candidates.append(&mut linuxbrew(report));
+    }
+    if cfg.include.contains(&OptionalSource::Jetbrains) {
+        for mut candidate in jetbrains(report) {
+            candidate.tags.push(IDE_BUNDLED.to_string());
+            candidates.push(candidate);
         }
-        if b.architecture == default_arch.as_str() && a.architecture != default_arch.as_str() {
-            return Ordering::Greater;
+    }
+    for candidate in candidates {
+        if let Some(jvm) = candidate_jvm(candidate, report) {
+            insert_jvm(jvms, jvm);
         }
     }
-    return version_test;
 }
 
-fn filter_ver(ver: &Option<String>, jvm: &Jvm) -> bool {
-    if !ver.is_none() {
-        let version = ver.as_ref().unwrap();
-        if version.contains("+") {
-            let sanitised_version = version.replace("+", "");
-            let compare_jvm_version = get_compare_version(jvm, &sanitised_version);
-            let compare = compare_version_values(&compare_jvm_version, &sanitised_version);
-            if compare.is_lt() {
-                return false;
+fn candidate_jvm(candidate: Candidate, report: &mut ScanReport) -> Option<Jvm> {
+    match fs::metadata(&candidate.path) {
+        Ok(metadata) if metadata.is_dir() => {}
+        Ok(_) => return None,
+        Err(error) => {
+            report.entry(&candidate.path, error);
+            return None;
+        }
+    }
+
+    let mut jvm = Jvm {
+        version: candidate.version,
+        name: candidate.name,
+        path: candidate.path.to_string_lossy().to_string(),
+        vendor: candidate.vendor,
+        architecture: candidate.architecture,
+        tags: candidate.tags,
+        ..Default::default()
+    };
+    // The release file is more reliable than the tool's naming, where there is one
+    if let Some(properties) = read_release(&candidate.path.join("release"), report) {
+        let version = release_value(&properties, "JAVA_VERSION");
+        if !version.is_empty() {
+            jvm.version = version;
+        }
+        let vendor = release_vendor(&properties);
+        if vendor != Vendor::Unknown {
+            jvm.vendor = vendor;
+        }
+        let architecture = Architecture::parse(&release_value(&properties, "OS_ARCH"));
+        if architecture != Architecture::Unknown {
+            jvm.architecture = architecture;
+        }
+        jvm.release = Release::from_properties(&properties);
+    }
+    // Nothing to select it by
+    if jvm.version.is_empty() {
+        return None;
+    }
+    Some(jvm)
+}
+
+// The JVMs in use, which may be outside any known location. $JAVA_HOME is what build tools use,
+// so it is marked active over the java on $PATH.
+fn collate_active_jvms(jvms: &mut HashMap<PathBuf, Jvm>, report: &mut ScanReport) {
+    let mut homes = vec![];
+    if let Some(value) = std::env::var_os("JAVA_HOME").filter(|value| !value.is_empty()) {
+        match java_home(&value) {
+            Some(home) => homes.push(home),
+            None => report.location(value, "JAVA_HOME is not a directory"),
+        }
+    }
+    if let Some(home) = std::env::var_os("PATH").and_then(|path| path_java_home(&path)) {
+        homes.push(home);
+    }
+
+    for home in &homes {
+        if let Some(jvm) = home_jvm(home, report) {
+            insert_jvm(jvms, jvm);
+        }
+    }
+    if let Some(jvm) = homes.first().and_then(|home| jvms.get_mut(home)) {
+        jvm.active = true;
+    }
+}
+
+// Priorities and the system default chosen with update-alternatives, adding any registered JVM
+// that is outside the locations searched
+#[cfg(target_os = "linux")]
+fn collate_alternatives(jvms: &mut HashMap<PathBuf, Jvm>, report: &mut ScanReport) {
+    let real_home = |java: &Path| fs::canonicalize(java).ok().and_then(|java| executable_home(&java));
+    for alternative in java_alternatives(report) {
+        let home = match real_home(&alternative.path) {
+            Some(home) => home,
+            None => {
+                report.entry(&alternative.path, "java alternative does not exist");
+                continue;
             }
-        } else {
-            let compare_jvm_version = get_compare_version(jvm, version);
-            let compare = compare_version_values(&version, &compare_jvm_version);
-            if compare.is_ne() {
-                return false;
+        };
+        if !jvms.contains_key(&home) {
+            if let Some(jvm) = home_jvm(&home, report) {
+                insert_jvm(jvms, jvm);
             }
         }
+        if let Some(jvm) = jvms.get_mut(&home) {
+            jvm.alternatives_priority = Some(alternative.priority);
+        }
+    }
+    if let Some(jvm) = current_alternative().and_then(|java| real_home(&java)).and_then(|home| jvms.get_mut(&home)) {
+        jvm.system_default = true;
     }
-    return true;
 }
 
-fn compare_version_values(version1: &String, version2: &String) -> Ordering {
-    // Normalise old style versions - e.g. 1.8 -> 8, 1.9 -> 9
-    let mut normalised1= version1.strip_prefix("1.")
-        .unwrap_or(version1.as_str()).to_string();
-    let mut normalised2= version2.strip_prefix("1.")
-        .unwrap_or(version2.as_str()).to_string();
-    // Normalise old sub versions e.g. 1.8.0_292 -> 1.8.0.292
-    normalised1 = normalised1.replace("_", ".");
-    normalised2 = normalised2.replace("_", ".");
-
-    let count_version1: Vec<String> =
-        normalised1.split(".").map(|s| s.to_string()).collect();
-    let count_version2: Vec<String> =
-        normalised2.split(".").map(|s| s.to_string()).collect();
-
-    let compare = Ordering::Equal;
-    let max_size = std::cmp::max(count_version1.len(), count_version2.len());
-
-    for i in 0..max_size {
-        if count_version1.get(i).is_none(){
-            return Ordering::Less
-        }
-        if count_version2.get(i).is_none(){
-            return Ordering::Greater
-        }
-        let version1_int = count_version1.get(i).unwrap().parse::<i32>().unwrap();
-        let version2_int = count_version2.get(i).unwrap().parse::<i32>().unwrap();
-        if version1_int > version2_int {
-            return Ordering::Greater
-        } else if version1_int < version2_int {
-            return Ordering::Less;
-        } else {
-            continue;
+// The OS package each JVM was installed from, if any
+#[cfg(target_os = "linux")]
+fn collate_packages(jvms: &mut [Jvm], report: &mut ScanReport) {
+    let homes: Vec<PathBuf> = jvms.iter()
+        .map(|jvm| fs::canonicalize(&jvm.path).unwrap_or_else(|_| PathBuf::from(&jvm.path)))
+        .collect();
+    let mut packages = owning_packages(&homes, report);
+    for (jvm, home) in jvms.iter_mut().zip(&homes) {
+        if let Some(package) = packages.remove(home) {
+            jvm.package = Some(package.name);
+            jvm.package_version = Some(package.version);
         }
     }
-    return compare;
 }
 
-fn get_compare_version(jvm: &Jvm, version: &String) -> String {
-    let version_count = version.matches('.').count();
-    let mut  jvm_version = jvm.version.clone();
+// What the home has to offer - whether it is a JDK, JRE or jlink runtime, and the tools in its
+// bin directory - once its release file has been read
+fn inspect_home(mut jvm: Jvm) -> Jvm {
+    let home = Path::new(&jvm.path);
+    jvm.jvm_type = JvmType::classify(home, &jvm.release.modules);
+    jvm.tools = home_tools(home);
+    jvm
+}
+
+// Highest priority first, keeping the version order for JVMs without one
+fn sort_by_priority(jvms: &mut [Jvm]) {
+    jvms.sort_by_key(|jvm| std::cmp::Reverse(jvm.alternatives_priority));
+}
+
+// A JVM home found directly rather than in a location, described by its release file
+fn home_jvm(home: &Path, report: &mut ScanReport) -> Option<Jvm> {
+    let properties = read_release(&home.join("release"), report)?;
+    // macOS homes sit inside a bundle named after the JVM
+    let name = match home.parent().and_then(|contents| contents.parent()) {
+        Some(bundle) if home.ends_with("Contents/Home") => bundle.file_name(),
+        _ => home.file_name(),
+    };
+    Some(Jvm {
+        version: release_value(&properties, "JAVA_VERSION"),
+        name: name.unwrap_or_default().to_string_lossy().to_string(),
+        architecture: Architecture::parse(&release_value(&properties, "OS_ARCH")),
+        path: home.to_string_lossy().to_string(),
+        vendor: release_vendor(&properties),
+        release: Release::from_properties(&properties),
+        ..Default::default()
+    })
+}
+
+// Adds a JVM, merging it into any found at the same real location (e.g. a jenv link to a system
+// JVM) so each home is only listed once
+fn insert_jvm(jvms: &mut HashMap<PathBuf, Jvm>, jvm: Jvm) {
+    let key = fs::canonicalize(&jvm.path).unwrap_or_else(|_| PathBuf::from(&jvm.path));
+    match jvms.entry(key) {
+        Entry::Vacant(entry) => {
+            entry.insert(jvm);
+        }
+        Entry::Occupied(mut entry) => {
+            let existing = entry.get_mut();
+            if existing.vendor == Vendor::Unknown {
+                existing.vendor = jvm.vendor;
+            }
+            if existing.release == Release::default() {
+                existing.release = jvm.release;
+            }
+            for tag in jvm.tags {
+                if !existing.tags.contains(&tag) {
+                    existing.tags.push(tag);
+                }
+            }
+        }
+    }
+}
 
-    // Normalise single digit compares for old style versions
-    if jvm.version.starts_with("1.") && version.matches('.').count() == 0 {
-        if !version.starts_with("1.") {
-            jvm_version = jvm_version.strip_prefix("1.")
-                .unwrap_or(jvm_version.as_str()).to_string();
+// Entries of a location directory, reporting the location if it can't be read
+fn read_location(path: &Path, report: &mut ScanReport) -> Vec<PathBuf> {
+    match fs::read_dir(path) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .collect(),
+        Err(error) => {
+            report.location(path, error);
+            vec![]
         }
     }
+}
 
-    let tmp_version: Vec<String> =
-        jvm_version.split_inclusive(".").map(|s| s.to_string()).collect();
-    let mut compare_version: String = String::new();
-    for i in 0..version_count + 1 {
-        compare_version.push_str(tmp_version.get(i).unwrap_or(&"".to_string()));
+// Release file properties, or None if there isn't a readable release file
+fn read_release(path: &Path, report: &mut ScanReport) -> Option<HashMap<String, String>> {
+    let release_file = File::open(path).ok()?;
+    match read(BufReader::new(release_file)) {
+        Ok(properties) => Some(properties),
+        Err(error) => {
+            report.entry(path, error);
+            None
+        }
     }
-    compare_version = compare_version.strip_suffix(".")
-        .unwrap_or(compare_version.as_str()).to_string();
-    compare_version
 }
 
-fn filter_arch(arch: &Option<String>, jvm: &Jvm) -> bool {
-    if !arch.is_none() {
-        if jvm.architecture != arch.as_ref().unwrap().to_string() {
-            return false;
+fn release_value(properties: &HashMap<String, String>, key: &str) -> String {
+    properties.get(key).unwrap_or(&"".to_string()).replace("\"", "")
+}
+
+fn release_vendor(properties: &HashMap<String, String>) -> Vendor {
+    Vendor::detect(&release_value(properties, "IMPLEMENTOR"),
+                   &release_value(properties, "IMPLEMENTOR_VERSION"),
+                   &release_value(properties, "JAVA_RUNTIME_VERSION"))
+}
+
+fn compare_boosting_architecture(a: &Jvm, b: &Jvm, default_arch: &Architecture) -> Ordering {
+    let version_test = compare_version_values(&b.version, &a.version);
+    if version_test == Ordering::Equal {
+        if b.architecture != *default_arch && a.architecture == *default_arch {
+            return Ordering::Less;
+        }
+        if b.architecture == *default_arch && a.architecture != *default_arch {
+            return Ordering::Greater;
         }
     }
+    return version_test;
+}
+
+fn filter_ide_bundled(include_ide_bundled: bool, jvm: &Jvm) -> bool {
+    include_ide_bundled || !jvm.is_ide_bundled()
+}
+
+fn filter_ver(ver: &Option<VersionSelector>, jvm: &Jvm) -> bool {
+    if let Some(selector) = ver {
+        return match JavaVersion::parse(&jvm.version) {
+            Some(jvm_version) => selector.matches(&jvm_version),
+            None => false,
+        };
+    }
     return true;
 }
 
-fn filter_name(name: &Option<String>, jvm: &Jvm) -> bool {
-    if !name.is_none() {
-        if jvm.name != name.as_ref().unwrap().to_string() {
-            return false;
+// Picks across the filtered JVMs, which are already sorted newest first with the default architecture boosted
+fn select_ver(ver: &Option<VersionSelector>, jvms: Vec<Jvm>) -> Vec<Jvm> {
+    match ver {
+        Some(VersionSelector::Latest) | Some(VersionSelector::LatestPatchOf(_)) => {
+            jvms.into_iter().take(1).collect()
         }
+        Some(VersionSelector::Oldest) => {
+            let oldest = match jvms.last() {
+                Some(jvm) => jvm.version.clone(),
+                None => return jvms,
+            };
+            jvms.into_iter()
+                .find(|jvm| compare_version_values(&jvm.version, &oldest).is_eq())
+                .into_iter()
+                .collect()
+        }
+        Some(VersionSelector::LtsOrNewer) => {
+            let newest_lts = jvms.iter()
+                .filter_map(|jvm| JavaVersion::parse(&jvm.version))
+                .map(|version| version.feature)
+                .find(|feature| is_lts(*feature));
+            match newest_lts {
+                Some(lts) => jvms.into_iter()
+                    .filter(|jvm| JavaVersion::parse(&jvm.version)
+                        .is_some_and(|version| version.feature >= lts))
+                    .collect(),
+                None => vec![],
+            }
+        }
+        _ => jvms,
+    }
+}
+
+fn compare_version_values(version1: &str, version2: &str) -> Ordering {
+    // Unparseable versions sort below any recognised version
+    JavaVersion::parse(version1).cmp(&JavaVersion::parse(version2))
+}
+
+fn filter_arch(arch: &Option<Architecture>, jvm: &Jvm) -> bool {
+    if let Some(arch) = arch {
+        return jvm.architecture == *arch;
+    }
+    return true;
+}
+
+fn filter_type(jvm_type: &Option<JvmType>, jvm: &Jvm) -> bool {
+    jvm_type.is_none_or(|jvm_type| jvm.jvm_type == jvm_type)
+}
+
+fn filter_modules(modules: &[String], jvm: &Jvm) -> bool {
+    if modules.is_empty() {
+        return true;
+    }
+    // Only worth reading the image itself when the release file doesn't list the modules
+    let available = if jvm.release.modules.is_empty() {
+        image_modules(&Path::new(&jvm.path).join("lib").join("modules")).unwrap_or_default()
+    } else {
+        jvm.release.modules.clone()
+    };
+    modules.iter().all(|module| available.contains(module))
+}
+
+fn filter_name(name: &Option<NamePattern>, jvm: &Jvm) -> bool {
+    if let Some(pattern) = name {
+        return pattern.matches(&jvm.name);
     }
     return true;
 }
 
+fn filter_vendor(vendor: &Option<Vendor>, jvm: &Jvm) -> bool {
+    // Compare by name so unrecognised vendors still match their implementor
+    vendor.as_ref().is_none_or(|vendor| jvm.vendor.to_string().eq_ignore_ascii_case(vendor.to_string().as_str()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixture::TempDir;
 
     #[test]
     fn test_filter_name() {
@@ -589,10 +1072,14 @@ mod tests {
                              "Eclipse Temurin 17",
                              "aarch64",
                              "/Library/Java/JavaVirtualMachines/temurin-17.jdk");
-        let same_name: Option<String> = Option::Some("Eclipse Temurin 17".to_string());
-        let different_name: Option<String> = Option::Some("Eclipse Temurin 11".to_string());
+        let same_name = name_pattern("Eclipse Temurin 17");
+        let different_name = name_pattern("Eclipse Temurin 11");
         assert_eq!(filter_name(&same_name, &jvm), true);
         assert_eq!(filter_name(&different_name, &jvm), false);
+        assert!(filter_name(&name_pattern("temurin"), &jvm));
+        assert!(filter_name(&name_pattern("*Temurin 1?"), &jvm));
+        assert!(!filter_name(&name_pattern("re:Temurin (11|21)"), &jvm));
+        assert!(filter_name(&Option::None, &jvm));
     }
 
     #[test]
@@ -601,10 +1088,14 @@ mod tests {
                              "Eclipse Temurin 17",
                              "aarch64",
                              "/Library/Java/JavaVirtualMachines/temurin-17.jdk");
-        let same_arch: Option<String> = Option::Some("aarch64".to_string());
-        let different_arch: Option<String> = Option::Some("x86_64".to_string());
+        let same_arch: Option<Architecture> = Option::Some(Architecture::parse("aarch64"));
+        let same_arch_alias: Option<Architecture> = Option::Some(Architecture::parse("arm64"));
+        let different_arch: Option<Architecture> = Option::Some(Architecture::parse("x86_64"));
+        let different_arch_alias: Option<Architecture> = Option::Some(Architecture::parse("amd64"));
         assert_eq!(filter_arch(&same_arch, &jvm), true);
+        assert!(filter_arch(&same_arch_alias, &jvm));
         assert_eq!(filter_arch(&different_arch, &jvm), false);
+        assert!(!filter_arch(&different_arch_alias, &jvm));
     }
 
     #[test]
@@ -613,10 +1104,10 @@ mod tests {
                              "Eclipse Temurin 17",
                              "aarch64",
                              "/Library/Java/JavaVirtualMachines/temurin-17.jdk");
-        let same_ver: Option<String> = Option::Some("17".to_string());
-        let different_ver_same_format: Option<String> = Option::Some("11".to_string());
-        let different_ver_diff_format: Option<String> = Option::Some("11.0.2".to_string());
-        let different_ver_diff_format2: Option<String> = Option::Some("11.0.2.1".to_string());
+        let same_ver = version_selector("17");
+        let different_ver_same_format = version_selector("11");
+        let different_ver_diff_format = version_selector("11.0.2");
+        let different_ver_diff_format2 = version_selector("11.0.2.1");
         assert_eq!(filter_ver(&same_ver, &jvm), true);
         assert_eq!(filter_ver(&different_ver_same_format, &jvm), false);
         assert_eq!(filter_ver(&different_ver_diff_format, &jvm), false);
@@ -624,23 +1115,26 @@ mod tests {
     }
 
     #[test]
-    fn test_compare_version() {
-        let jvm = create_jvm("17.0.2",
-                             "Eclipse Temurin 17",
-                             "aarch64",
-                             "/Library/Java/JavaVirtualMachines/temurin-17.jdk");
-        assert_eq!(get_compare_version(&jvm, &"8+".to_string()), "17");
-        assert_eq!(get_compare_version(&jvm, &"17".to_string()), "17");
-        assert_eq!(get_compare_version(&jvm, &"17.1".to_string()), "17.0");
-        assert_eq!(get_compare_version(&jvm, &"17.0.1".to_string()), "17.0.2");
-        assert_eq!(get_compare_version(&jvm, &"17.0.1.1".to_string()), "17.0.2");
-        assert_eq!(get_compare_version(&jvm, &"17.0.1_bau".to_string()), "17.0.2");
-        let jvm2 = create_jvm("1.8.0",
+    fn test_filter_version_formats() {
+        let jvm = create_jvm("1.8.0_292-b10",
                              "AdoptOpenJDK 8",
                              "aarch64",
                              "/Library/Java/JavaVirtualMachines/adoptopenjdk-1.8.0.jdk");
-        assert_eq!(get_compare_version(&jvm2, &"8".to_string()), "8");
-
+        assert!(filter_ver(&version_selector("8"), &jvm));
+        assert!(filter_ver(&version_selector("1.8"), &jvm));
+        assert!(filter_ver(&version_selector("8+"), &jvm));
+        assert!(!filter_ver(&version_selector("11+"), &jvm));
+        let jvm2 = create_jvm("21-ea",
+                              "OpenJDK 21",
+                              "aarch64",
+                              "/Library/Java/JavaVirtualMachines/openjdk-21.jdk");
+        assert!(filter_ver(&version_selector("21"), &jvm2));
+        assert!(filter_ver(&version_selector("17+"), &jvm2));
+        let jvm3 = create_jvm("",
+                              "Unknown",
+                              "aarch64",
+                              "/Library/Java/JavaVirtualMachines/unknown.jdk");
+        assert!(!filter_ver(&version_selector("17"), &jvm3));
     }
 
     #[test]
@@ -661,10 +1155,10 @@ mod tests {
 
     #[cfg(any(target_os = "linux", target_os = "macos"))]
     #[test]
-    fn test_trim_string(){
-        assert_eq!(trim_string("Arm\n"), "Arm");
-        assert_eq!(trim_string("Arm\r\n"), "Arm");
-        assert_eq!(trim_string("Arm"), "Arm");
+    fn test_uname(){
+        let (os, machine) = uname().unwrap();
+        assert!(!os.is_empty());
+        assert_eq!(Architecture::parse(&machine), Architecture::parse(std::env::consts::ARCH));
     }
 
     #[test]
@@ -683,9 +1177,94 @@ mod tests {
         check_version(jvms.clone(), "17+", 0);
     }
 
+    #[test]
+    fn test_compare_version_ranges(){
+        let jvm1: Jvm = create_jvm("11.0.2",
+                                   "Eclipse Temurin 11",
+                                   "aarch64",
+                                   "/Library/Java/JavaVirtualMachines/temurin-11-aarch64.jdk");
+        let jvm2: Jvm = create_jvm("17.0.1",
+                                   "Eclipse Temurin 17",
+                                   "aarch64",
+                                   "/Library/Java/JavaVirtualMachines/temurin-17-aarch64.jdk");
+
+        let jvms: Vec<Jvm> = vec![jvm1.clone(), jvm2.clone()];
+        check_version(jvms.clone(), ">=11 <17", 1);
+        check_version(jvms.clone(), "11..=17", 2);
+        check_version(jvms.clone(), "[11,17)", 1);
+        check_version(jvms.clone(), "~17.0.3", 0);
+        check_version(jvms.clone(), "^11", 1);
+        check_version(jvms.clone(), "!=19", 2);
+    }
+
+    #[test]
+    fn test_select_version(){
+        let jvm1: Jvm = create_jvm("11.0.2",
+                                   "Eclipse Temurin 11",
+                                   "aarch64",
+                                   "/Library/Java/JavaVirtualMachines/temurin-11-aarch64.jdk");
+        let jvm2: Jvm = create_jvm("17.0.1",
+                                   "Eclipse Temurin 17",
+                                   "aarch64",
+                                   "/Library/Java/JavaVirtualMachines/temurin-17-aarch64.jdk");
+        let jvm3: Jvm = create_jvm("17.0.9",
+                                   "Eclipse Temurin 17",
+                                   "aarch64",
+                                   "/Library/Java/JavaVirtualMachines/temurin-17.0.9-aarch64.jdk");
+        let jvm4: Jvm = create_jvm("19.0.2",
+                                   "Eclipse Temurin 19",
+                                   "aarch64",
+                                   "/Library/Java/JavaVirtualMachines/temurin-19-aarch64.jdk");
+        let jvm5: Jvm = create_jvm("8",
+                                   "Adopt OpenJDK 8",
+                                   "aarch64",
+                                   "/Library/Java/JavaVirtualMachines/adoptopenjdk-8-aarch64.jdk");
+        let jvm6: Jvm = create_jvm("8",
+                                   "Adopt OpenJDK 8",
+                                   "x86_64",
+                                   "/Library/Java/JavaVirtualMachines/adoptopenjdk-8-x86_64.jdk");
+
+        let mut jvms: Vec<Jvm> = vec![jvm1.clone(), jvm2.clone(), jvm3.clone(), jvm4.clone(), jvm5.clone(), jvm6.clone()];
+        jvms.sort_by(|a, b| compare_boosting_architecture(a, b, &Architecture::Aarch64));
+
+        assert_eq!(select_version(jvms.clone(), "latest"), vec![jvm4.clone()]);
+        assert_eq!(select_version(jvms.clone(), "oldest"), vec![jvm5.clone()]);
+        assert_eq!(select_version(jvms.clone(), "lts").first(), Some(&jvm3));
+        assert_eq!(select_version(jvms.clone(), "lts").len(), 5);
+        assert_eq!(select_version(jvms.clone(), "lts+"), vec![jvm4.clone(), jvm3.clone(), jvm2.clone()]);
+        assert_eq!(select_version(jvms.clone(), "latest-patch-of 17"), vec![jvm3.clone()]);
+        assert_eq!(select_version(jvms.clone(), "latest-patch-of 21"), vec![]);
+        assert_eq!(select_version(jvms.clone(), "11+").len(), 4);
+    }
+
+    #[test]
+    fn test_select_version_skips_ide_bundled() {
+        let mut jbr = create_jvm("21.0.1", "IDEA-U 233.11799.241", "x86_64", "/opt/idea/jbr");
+        jbr.tags.push(IDE_BUNDLED.to_string());
+        let temurin = create_jvm("17.0.9", "Eclipse Temurin 17", "x86_64", "/usr/lib/jvm/temurin-17-jdk-amd64");
+        let jvms = [jbr.clone(), temurin.clone()];
+        let considered = |include_ide_bundled| jvms.iter()
+            .filter(|tmp| filter_ide_bundled(include_ide_bundled, tmp))
+            .cloned()
+            .collect::<Vec<Jvm>>();
+
+        assert_eq!(select_version(considered(false), "latest"), vec![temurin.clone()]);
+        assert_eq!(select_version(considered(false), "lts+"), vec![temurin.clone()]);
+        assert_eq!(select_version(considered(true), "latest"), vec![jbr.clone()]);
+        assert_eq!(select_version(considered(true), "lts+"), vec![jbr]);
+    }
+
+    fn select_version(jvms: Vec<Jvm>, version: &str) -> Vec<Jvm> {
+        let selector = version_selector(version);
+        let filtered: Vec<Jvm> = jvms.into_iter()
+            .filter(|tmp| filter_ver(&selector, tmp))
+            .collect();
+        select_ver(&selector, filtered)
+    }
+
     fn check_version(jvms: Vec<Jvm>, version: &str, number: usize) {
         let result: &Vec<Jvm> = &jvms.into_iter()
-            .filter(|tmp| filter_ver(&Option::Some(version.to_string()), tmp))
+            .filter(|tmp| filter_ver(&version_selector(version), tmp))
             .collect();
         assert_eq!(result.len(), number);
     }
@@ -713,18 +1292,231 @@ mod tests {
         let gold_ordered_x86_64 :Vec<Jvm> = vec![jvm3.clone(), jvm2.clone(), jvm1.clone(), jvm4.clone()];
         let mut jvms :Vec<Jvm> = vec![jvm1.clone(), jvm2.clone(), jvm3.clone(), jvm4.clone()];
 
-        jvms.sort_by(|a, b| compare_boosting_architecture(a, b, &"aarch64".to_string()));
+        jvms.sort_by(|a, b| compare_boosting_architecture(a, b, &Architecture::Aarch64));
         assert_eq!(jvm_vec_compare(gold_ordered_aarch64, &jvms), true);
-        jvms.sort_by(|a, b| compare_boosting_architecture(a, b, &"x86_64".to_string()));
+        jvms.sort_by(|a, b| compare_boosting_architecture(a, b, &Architecture::X86_64));
         assert_eq!(jvm_vec_compare(gold_ordered_x86_64, &jvms), true);
     }
 
+    #[test]
+    fn test_prerelease(){
+        let jvm1: Jvm = create_jvm("21-ea",
+                                   "OpenJDK 21",
+                                   "x86_64",
+                                   "/usr/lib/jvm/jdk-21");
+        let jvm2: Jvm = create_jvm("17.0.2+8",
+                                   "Eclipse Temurin 17",
+                                   "x86_64",
+                                   "/usr/lib/jvm/temurin-17");
+        assert!(jvm1.is_prerelease());
+        assert!(!jvm2.is_prerelease());
+        assert_eq!(format_detailed(&jvm1), "21-ea (x86_64) \"OpenJDK 21\" - /usr/lib/jvm/jdk-21 [pre-release]");
+        assert_eq!(format_detailed(&jvm2), "17.0.2+8 (x86_64) \"Eclipse Temurin 17\" - /usr/lib/jvm/temurin-17");
+    }
+
+    #[test]
+    fn test_filter_vendor() {
+        let mut jvm = create_jvm("17.0.2",
+                                 "Eclipse Temurin 17",
+                                 "aarch64",
+                                 "/Library/Java/JavaVirtualMachines/temurin-17.jdk");
+        jvm.vendor = Vendor::Temurin;
+        assert!(filter_vendor(&Option::Some(Vendor::parse("temurin")), &jvm));
+        assert!(filter_vendor(&Option::Some(Vendor::parse("adoptium")), &jvm));
+        assert!(!filter_vendor(&Option::Some(Vendor::parse("zulu")), &jvm));
+        assert!(filter_vendor(&Option::None, &jvm));
+        jvm.vendor = Vendor::Other("Homebrew".to_string());
+        assert!(filter_vendor(&Option::Some(Vendor::parse("homebrew")), &jvm));
+        assert_eq!(format_detailed(&jvm), "17.0.2 (aarch64, Homebrew) \"Eclipse Temurin 17\" - /Library/Java/JavaVirtualMachines/temurin-17.jdk");
+    }
+
+    #[test]
+    fn test_filter_modules() {
+        let mut jvm = create_jvm("17.0.9", "liberica-full-17", "x64", "/opt/jvms/liberica-full-17");
+        jvm.release.modules = vec!["java.base".to_string(), "java.desktop".to_string(), "javafx.controls".to_string()];
+        assert!(filter_modules(&[], &jvm));
+        assert!(filter_modules(&["javafx.controls".to_string()], &jvm));
+        assert!(filter_modules(&["javafx.controls".to_string(), "java.desktop".to_string()], &jvm));
+        assert!(!filter_modules(&["javafx.controls".to_string(), "jdk.incubator.vector".to_string()], &jvm));
+        // Without a MODULES entry or a lib/modules image there is nothing to match
+        jvm.release.modules.clear();
+        assert!(!filter_modules(&["java.base".to_string()], &jvm));
+    }
+
+    #[test]
+    fn test_filter_type() {
+        let mut jvm = create_jvm("21.0.1", "app-runtime", "x64", "/opt/app/runtime");
+        jvm.jvm_type = JvmType::Runtime;
+        assert!(filter_type(&Option::Some(JvmType::Runtime), &jvm));
+        assert!(!filter_type(&Option::Some(JvmType::Jdk), &jvm));
+        assert!(filter_type(&Option::None, &jvm));
+        assert_eq!(format_detailed(&jvm), "21.0.1 (x86_64, runtime) \"app-runtime\" - /opt/app/runtime");
+        assert_eq!(serde_json::to_value(&jvm).unwrap()["jvm_type"], "runtime");
+    }
+
+    #[test]
+    fn test_release_vendor() {
+        let properties = HashMap::from(
+            [("IMPLEMENTOR".to_string(), "\"Private Build\"".to_string()),
+                ("JAVA_RUNTIME_VERSION".to_string(), "\"17.0.9+9-Ubuntu-122.04\"".to_string())]);
+        assert_eq!(release_vendor(&properties), Vendor::Ubuntu);
+        assert_eq!(release_value(&properties, "IMPLEMENTOR"), "Private Build");
+        assert_eq!(release_value(&properties, "IMPLEMENTOR_VERSION"), "");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_collate_skips_unreadable() {
+        let root = TempDir::new("collate");
+        let home = root.join("jdk-17.0.2");
+        fs::create_dir_all(&home).unwrap();
+        fs::write(home.join("release"), "JAVA_VERSION=\"17.0.2\"\nOS_ARCH=\"amd64\"\n").unwrap();
+        std::os::unix::fs::symlink(root.join("removed"), root.join("dangling")).unwrap();
+
+        let mut report = ScanReport::default();
+        let mut paths = read_location(&root.join("missing"), &mut report);
+        paths.append(&mut read_location(&root, &mut report));
+        let mut jvms = HashMap::new();
+        collate_location_jvms(&mut jvms, paths, &mut report);
+
+        assert!(jvms.values().any(|jvm| jvm.path == home.to_string_lossy()));
+        assert_eq!(report.locations.len(), 1);
+        assert_eq!(report.locations[0].path, root.join("missing").to_string_lossy());
+        assert_eq!(report.entries.len(), 1);
+        assert_eq!(report.entries[0].path, root.join("dangling").to_string_lossy());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_candidate_jvm() {
+        let root = TempDir::new("candidate");
+        fs::create_dir_all(root.join("17.0.9-tem")).unwrap();
+        fs::create_dir_all(root.join("21.0.1-zulu")).unwrap();
+        fs::write(root.join("21.0.1-zulu/release"),
+                  "JAVA_VERSION=\"21.0.1\"\nOS_ARCH=\"aarch64\"\nIMPLEMENTOR=\"Azul Systems, Inc.\"\n").unwrap();
+        let candidate = |name: &str, version: &str, vendor: Vendor| Candidate {
+            path: root.join(name),
+            name: name.to_string(),
+            version: version.to_string(),
+            vendor,
+            tags: vec!["sdkman current".to_string()],
+            ..Default::default()
+        };
+
+        let mut report = ScanReport::default();
+        let jvm = candidate_jvm(candidate("17.0.9-tem", "17.0.9", Vendor::Temurin), &mut report).unwrap();
+        assert_eq!(jvm.version, "17.0.9");
+        assert_eq!(jvm.vendor, Vendor::Temurin);
+        assert_eq!(jvm.architecture, Architecture::Unknown);
+        assert!(format_detailed(&jvm).ends_with("17.0.9-tem [sdkman current]"));
+
+        let jvm = candidate_jvm(candidate("21.0.1-zulu", "", Vendor::Unknown), &mut report).unwrap();
+        assert_eq!(jvm.version, "21.0.1");
+        assert_eq!(jvm.vendor, Vendor::Zulu);
+        assert_eq!(jvm.architecture, Architecture::Aarch64);
+
+        assert_eq!(candidate_jvm(candidate("local", "", Vendor::Unknown), &mut report), None);
+        assert_eq!(report.entries.len(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_insert_jvm_merges_same_home() {
+        let root = TempDir::new("merge");
+        fs::create_dir_all(root.join("java-17-openjdk-amd64")).unwrap();
+        std::os::unix::fs::symlink(root.join("java-17-openjdk-amd64"), root.join("17")).unwrap();
+
+        let mut jvms = HashMap::new();
+        let system = create_jvm("17.0.9", "java-17-openjdk-amd64", "x86_64",
+                                &root.join("java-17-openjdk-amd64").to_string_lossy());
+        let mut jenv = create_jvm("17.0.9", "openjdk64-17.0.9", "x86_64", &root.join("17").to_string_lossy());
+        jenv.vendor = Vendor::Debian;
+        jenv.tags = vec!["jenv global".to_string()];
+        insert_jvm(&mut jvms, system.clone());
+        insert_jvm(&mut jvms, jenv);
+
+        let jvms: Vec<Jvm> = jvms.into_values().collect();
+        assert_eq!(jvms.len(), 1);
+        assert_eq!(jvms[0].name, system.name);
+        assert_eq!(jvms[0].vendor, Vendor::Debian);
+        assert_eq!(jvms[0].tags, vec!["jenv global"]);
+    }
+
+    #[test]
+    fn test_active_output() {
+        let mut jvm = create_jvm("17.0.9", "temurin-17.0.9", "amd64", "/opt/jvms/temurin-17.0.9");
+        jvm.vendor = Vendor::Temurin;
+        jvm.active = true;
+        assert_eq!(format_detailed(&jvm), "17.0.9 (x86_64, Temurin) \"temurin-17.0.9\" - /opt/jvms/temurin-17.0.9 [active]");
+
+        let json = serde_json::to_value(&jvm).unwrap();
+        assert_eq!(json["architecture"], "x86_64");
+        assert_eq!(json["vendor"], "Temurin");
+        assert_eq!(json["path"], "/opt/jvms/temurin-17.0.9");
+        assert_eq!(json["active"], true);
+    }
+
+    #[test]
+    fn test_sort_by_priority() {
+        let mut jvm21 = create_jvm("21.0.1", "java-21-openjdk-amd64", "amd64", "/usr/lib/jvm/java-21-openjdk-amd64");
+        let mut jvm17 = create_jvm("17.0.9", "java-17-openjdk-amd64", "amd64", "/usr/lib/jvm/java-17-openjdk-amd64");
+        let jvm11 = create_jvm("11.0.21", "temurin-11", "amd64", "/opt/jvms/temurin-11");
+        let jvm8 = create_jvm("1.8.0_392", "java-8-openjdk-amd64", "amd64", "/usr/lib/jvm/java-8-openjdk-amd64");
+        jvm21.alternatives_priority = Some(2111);
+        jvm17.alternatives_priority = Some(2411);
+        jvm17.system_default = true;
+
+        let mut jvms = vec![jvm21.clone(), jvm17.clone(), jvm11.clone(), jvm8.clone()];
+        sort_by_priority(&mut jvms);
+        assert!(jvm_vec_compare(jvms, &vec![jvm17.clone(), jvm21, jvm11, jvm8]));
+        assert!(format_detailed(&jvm17).ends_with(" [system default] [priority 2411]"));
+    }
+
+    #[test]
+    fn test_package_output() {
+        let mut jvm = create_jvm("17.0.9", "java-17-openjdk-amd64", "amd64", "/usr/lib/jvm/java-17-openjdk-amd64");
+        jvm.package = Some("openjdk-17-jdk-headless".to_string());
+        jvm.package_version = Some("17.0.9+9-1~deb12u1".to_string());
+        assert!(format_detailed(&jvm).ends_with(" [package openjdk-17-jdk-headless 17.0.9+9-1~deb12u1]"));
+        assert_eq!(serde_json::to_value(&jvm).unwrap()["package"], "openjdk-17-jdk-headless");
+
+        jvm.package_version = Some("".to_string());
+        assert!(format_detailed(&jvm).ends_with(" [package openjdk-17-jdk-headless]"));
+    }
+
+    #[test]
+    fn test_release_output() {
+        let mut jvm = create_jvm("17.0.9", "temurin-17", "x64", "/opt/jvms/temurin-17");
+        assert_eq!(format_detailed(&jvm).lines().count(), 1);
+
+        jvm.release.implementor = Some("Eclipse Adoptium".to_string());
+        jvm.release.libc = Some("musl".to_string());
+        jvm.release.modules = vec!["java.base".to_string(), "jdk.compiler".to_string()];
+        jvm.tools = vec!["java".to_string(), "javac".to_string()];
+        assert!(format_detailed(&jvm).ends_with("/opt/jvms/temurin-17\n    tools: java javac\n    IMPLEMENTOR=\"Eclipse Adoptium\"\n    \
+                                                 LIBC=\"musl\"\n    MODULES=\"java.base jdk.compiler\""));
+        let json = serde_json::to_value(&jvm).unwrap();
+        assert_eq!(json["release"]["libc"], "musl");
+        assert_eq!(json["release"]["modules"][1], "jdk.compiler");
+        assert_eq!(json["release"]["graalvm_version"], serde_json::Value::Null);
+        assert_eq!(json["tools"][1], "javac");
+    }
+
+    fn name_pattern(name: &str) -> Option<NamePattern> {
+        Option::Some(NamePattern::parse(name).unwrap())
+    }
+
+    fn version_selector(version: &str) -> Option<VersionSelector> {
+        Option::Some(VersionSelector::parse(version).unwrap())
+    }
+
     fn create_jvm(version: &str, name: &str, architecture: &str, path: &str) -> Jvm {
         return Jvm {
             version: version.to_string(),
             name: name.to_string(),
-            architecture: architecture.to_string(),
-            path: path.to_string()
+            architecture: Architecture::parse(architecture),
+            path: path.to_string(),
+            ..Default::default()
         };
     }
 
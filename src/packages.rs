@@ -0,0 +1,169 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::report::ScanReport;
+
+const DPKG_INFO: &str = "/var/lib/dpkg/info";
+const DPKG_STATUS: &str = "/var/lib/dpkg/status";
+const RPM_DATABASES: [&str; 2] = ["/var/lib/rpm", "/usr/lib/sysimage/rpm"];
+
+// Files that identify the package a JVM home came from, best first - javac belongs to the JDK
+// package where there is one, while the JRE package it depends on owns java
+const OWNED_FILES: [&str; 2] = ["bin/javac", "bin/java"];
+
+/// An installed OS package
+#[derive(Clone, Debug, PartialEq)]
+pub struct Package {
+    pub name: String,
+    pub version: String,
+}
+
+/// Packages that installed each JVM home, from the dpkg or rpm database. Homes that weren't
+/// installed by a package (e.g. unpacked by hand) are left out.
+pub fn owning_packages(homes: &[PathBuf], report: &mut ScanReport) -> HashMap<PathBuf, Package> {
+    let files: Vec<(&PathBuf, PathBuf)> = homes.iter()
+        .flat_map(|home| OWNED_FILES.iter().map(move |file| (home, home.join(file))))
+        .filter(|(_, file)| file.exists())
+        .collect();
+
+    let mut packages = HashMap::new();
+    if Path::new(DPKG_INFO).is_dir() {
+        let targets: HashSet<PathBuf> = files.iter().map(|(_, file)| file.clone()).collect();
+        let owners = dpkg_owners(Path::new(DPKG_INFO), &targets, report);
+        let versions = match fs::read_to_string(DPKG_STATUS) {
+            Ok(status) => parse_dpkg_status(&status),
+            Err(error) => {
+                report.location(DPKG_STATUS, error);
+                HashMap::new()
+            }
+        };
+        for (home, file) in &files {
+            if let Some(name) = owners.get(file).filter(|_| !packages.contains_key(*home)) {
+                let version = versions.get(name).cloned().unwrap_or_default();
+                packages.insert(home.to_path_buf(), Package { name: name.clone(), version });
+            }
+        }
+    } else if RPM_DATABASES.iter().any(|database| Path::new(database).is_dir()) {
+        for (home, file) in &files {
+            if packages.contains_key(*home) {
+                continue;
+            }
+            if let Some(package) = rpm_owner(file) {
+                packages.insert(home.to_path_buf(), package);
+            }
+        }
+    }
+    packages
+}
+
+/// Owning package of each target file, from the file lists dpkg keeps per package
+/// (e.g. openjdk-17-jdk-headless:amd64.list)
+pub fn dpkg_owners(info: &Path, targets: &HashSet<PathBuf>, report: &mut ScanReport) -> HashMap<PathBuf, String> {
+    let mut owners = HashMap::new();
+    let lists = match fs::read_dir(info) {
+        Ok(entries) => entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()),
+        Err(error) => {
+            report.location(info, error);
+            return owners;
+        }
+    };
+    for list in lists.filter(|path| path.extension().is_some_and(|extension| extension == "list")) {
+        let content = match fs::read_to_string(&list) {
+            Ok(content) => content,
+            Err(error) => {
+                report.entry(&list, error);
+                continue;
+            }
+        };
+        // Multi-arch packages have the architecture after the name
+        let stem = list.file_stem().unwrap_or_default().to_string_lossy().to_string();
+        let name = stem.split(':').next().unwrap_or_default();
+        for line in content.lines().filter(|line| targets.contains(Path::new(line))) {
+            owners.insert(PathBuf::from(line), name.to_string());
+        }
+    }
+    owners
+}
+
+/// Installed package versions by name, from the dpkg status file
+pub fn parse_dpkg_status(status: &str) -> HashMap<String, String> {
+    let mut versions = HashMap::new();
+    for paragraph in status.split("\n\n") {
+        let field = |name: &str| paragraph.lines()
+            .find_map(|line| line.strip_prefix(name))
+            .map(|value| value.trim().to_string());
+        if let (Some(package), Some(version)) = (field("Package:"), field("Version:")) {
+            versions.insert(package, version);
+        }
+    }
+    versions
+}
+
+// The rpm database is only readable through rpm itself
+fn rpm_owner(file: &Path) -> Option<Package> {
+    let output = Command::new("rpm")
+        .args(["-qf", "--queryformat", "%{NAME}\t%{VERSION}-%{RELEASE}\n"])
+        .arg(file)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_rpm_query(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Package from rpm's name and version query output
+pub fn parse_rpm_query(output: &str) -> Option<Package> {
+    let (name, version) = output.lines().next()?.split_once('\t')?;
+    Some(Package { name: name.to_string(), version: version.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::TempDir;
+
+    #[test]
+    fn test_dpkg_owners() {
+        let info = TempDir::new("dpkg");
+        fs::write(info.join("openjdk-17-jre-headless:amd64.list"),
+                  "/.\n/usr/lib/jvm/java-17-openjdk-amd64\n/usr/lib/jvm/java-17-openjdk-amd64/bin/java\n").unwrap();
+        fs::write(info.join("openjdk-17-jdk-headless:amd64.list"),
+                  "/usr/lib/jvm/java-17-openjdk-amd64\n/usr/lib/jvm/java-17-openjdk-amd64/bin/javac\n").unwrap();
+        fs::write(info.join("openjdk-17-jdk-headless:amd64.md5sums"),
+                  "/usr/lib/jvm/java-17-openjdk-amd64/bin/java\n").unwrap();
+
+        let targets = HashSet::from([
+            PathBuf::from("/usr/lib/jvm/java-17-openjdk-amd64/bin/javac"),
+            PathBuf::from("/usr/lib/jvm/java-17-openjdk-amd64/bin/java"),
+            PathBuf::from("/opt/jvms/temurin-17/bin/java"),
+        ]);
+        let mut report = ScanReport::default();
+        let owners = dpkg_owners(&info, &targets, &mut report);
+
+        assert_eq!(owners.len(), 2);
+        assert_eq!(owners[Path::new("/usr/lib/jvm/java-17-openjdk-amd64/bin/javac")], "openjdk-17-jdk-headless");
+        assert_eq!(owners[Path::new("/usr/lib/jvm/java-17-openjdk-amd64/bin/java")], "openjdk-17-jre-headless");
+    }
+
+    #[test]
+    fn test_parse_dpkg_status() {
+        let status = "Package: openjdk-17-jre-headless\nStatus: install ok installed\nArchitecture: amd64\n\
+                      Version: 17.0.9+9-1~deb12u1\nDescription: OpenJDK Java runtime\n Minimal runtime\n\n\
+                      Package: tzdata\nVersion: 2024a-0+deb12u1\n";
+        let versions = parse_dpkg_status(status);
+        assert_eq!(versions["openjdk-17-jre-headless"], "17.0.9+9-1~deb12u1");
+        assert_eq!(versions["tzdata"], "2024a-0+deb12u1");
+    }
+
+    #[test]
+    fn test_parse_rpm_query() {
+        assert_eq!(parse_rpm_query("java-17-openjdk-headless\t17.0.9.0.9-3.fc39\n"), Some(Package {
+            name: "java-17-openjdk-headless".to_string(),
+            version: "17.0.9.0.9-3.fc39".to_string(),
+        }));
+        assert_eq!(parse_rpm_query(""), None);
+    }
+}
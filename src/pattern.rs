@@ -0,0 +1,79 @@
+use glob::{MatchOptions, Pattern};
+use regex::Regex;
+
+const REGEX_PREFIX: &str = "re:";
+
+/// Name filter, matched as a regular expression when prefixed with "re:", as a glob when it
+/// contains wildcards (e.g. *temurin*), or as a case-insensitive substring otherwise
+#[derive(Clone, Debug)]
+pub enum NamePattern {
+    Substring(String),
+    Glob(Pattern),
+    Regex(Regex),
+}
+
+impl NamePattern {
+    pub fn parse(value: &str) -> Result<NamePattern, String> {
+        if let Some(expression) = value.strip_prefix(REGEX_PREFIX) {
+            return Regex::new(expression)
+                .map(NamePattern::Regex)
+                .map_err(|error| format!("Invalid name regex '{}': {}", expression, error));
+        }
+        if value.contains(['*', '?', '[']) {
+            return Pattern::new(value)
+                .map(NamePattern::Glob)
+                .map_err(|error| format!("Invalid name pattern '{}': {}", value, error));
+        }
+        Ok(NamePattern::Substring(value.to_lowercase()))
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            NamePattern::Substring(substring) => name.to_lowercase().contains(substring.as_str()),
+            NamePattern::Glob(pattern) => pattern.matches_with(name, MatchOptions {
+                case_sensitive: false,
+                ..MatchOptions::new()
+            }),
+            NamePattern::Regex(regex) => regex.is_match(name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, name: &str) -> bool {
+        NamePattern::parse(pattern).unwrap().matches(name)
+    }
+
+    #[test]
+    fn test_substring() {
+        assert!(matches("temurin", "Eclipse Temurin 17"));
+        assert!(matches("Eclipse Temurin 17", "Eclipse Temurin 17"));
+        assert!(matches("openjdk-amd64", "java-17-openjdk-amd64"));
+        assert!(!matches("zulu", "Eclipse Temurin 17"));
+    }
+
+    #[test]
+    fn test_glob() {
+        assert!(matches("*temurin*", "Eclipse Temurin 17"));
+        assert!(matches("java-1?-openjdk-*", "java-17-openjdk-amd64"));
+        assert!(matches("java-[0-9]*-openjdk-*", "java-8-openjdk-amd64"));
+        assert!(!matches("temurin*", "Eclipse Temurin 17"));
+    }
+
+    #[test]
+    fn test_regex() {
+        assert!(matches("re:^Eclipse Temurin (11|17)$", "Eclipse Temurin 17"));
+        assert!(!matches("re:^Eclipse Temurin (11|17)$", "Eclipse Temurin 21"));
+        assert!(!matches("re:temurin", "Eclipse Temurin 17"));
+        assert!(matches("re:(?i)temurin", "Eclipse Temurin 17"));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(NamePattern::parse("re:(").is_err());
+        assert!(NamePattern::parse("[temurin").is_err());
+    }
+}
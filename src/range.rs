@@ -0,0 +1,271 @@
+use crate::version::JavaVersion;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operator {
+    Prefix,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+/// Single version constraint, compared only to the precision given - e.g. "<17" excludes 17.0.2
+#[derive(Clone, Debug)]
+struct Comparator {
+    operator: Operator,
+    version: JavaVersion,
+}
+
+impl Comparator {
+    fn matches(&self, version: &JavaVersion) -> bool {
+        let compare = version.cmp_prefix(&self.version);
+        match self.operator {
+            Operator::Prefix => compare.is_eq(),
+            Operator::Ne => compare.is_ne(),
+            Operator::Gt => compare.is_gt(),
+            Operator::Ge => compare.is_ge(),
+            Operator::Lt => compare.is_lt(),
+            Operator::Le => compare.is_le(),
+        }
+    }
+}
+
+/// Set of version constraints that must all hold, parsed from expressions such as
+/// "17", "11+", ">=11 <17", "11..17", "~17.0.3", "^11", "!=19" or "[11,17)"
+#[derive(Clone, Debug)]
+pub struct VersionRange {
+    comparators: Vec<Comparator>,
+}
+
+impl VersionRange {
+    pub fn parse(value: &str) -> Result<VersionRange, String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err("Version range is empty".to_string());
+        }
+
+        let comparators = if value.starts_with(['[', '(']) {
+            parse_maven(value)?
+        } else {
+            let mut comparators = Vec::new();
+            for token in tokenize(value) {
+                comparators.append(&mut parse_token(&token)?);
+            }
+            comparators
+        };
+        Ok(VersionRange { comparators })
+    }
+
+    pub fn matches(&self, version: &JavaVersion) -> bool {
+        self.comparators.iter().all(|comparator| comparator.matches(version))
+    }
+}
+
+// Splits on whitespace and commas, re-attaching operators written apart from their version - e.g. ">= 11"
+fn tokenize(value: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut pending = String::new();
+    for part in value.split([' ', '\t', ',']).filter(|part| !part.is_empty()) {
+        if part.chars().all(|c| "<>=!~^".contains(c)) {
+            pending.push_str(part);
+        } else {
+            tokens.push(format!("{}{}", pending, part));
+            pending.clear();
+        }
+    }
+    if !pending.is_empty() {
+        tokens.push(pending);
+    }
+    tokens
+}
+
+fn parse_token(token: &str) -> Result<Vec<Comparator>, String> {
+    if let Some((lower, upper)) = token.split_once("..") {
+        // Rust style ranges - e.g. 11..17, 11..=17, 11.., ..17
+        let (upper, inclusive) = match upper.strip_prefix('=') {
+            Some(upper) => (upper, true),
+            None => (upper, false),
+        };
+        let mut comparators = Vec::new();
+        if !lower.is_empty() {
+            comparators.push(comparator(Operator::Ge, lower)?);
+        }
+        if !upper.is_empty() {
+            comparators.push(comparator(if inclusive { Operator::Le } else { Operator::Lt }, upper)?);
+        }
+        if comparators.is_empty() {
+            return Err(format!("Invalid version range '{}'", token));
+        }
+        return Ok(comparators);
+    }
+
+    if let Some(version) = token.strip_suffix('+') {
+        return Ok(vec![comparator(Operator::Ge, version)?]);
+    }
+
+    let prefixes = [
+        (">=", Operator::Ge),
+        ("<=", Operator::Le),
+        ("!=", Operator::Ne),
+        ("==", Operator::Prefix),
+        (">", Operator::Gt),
+        ("<", Operator::Lt),
+        ("=", Operator::Prefix),
+    ];
+    for (prefix, operator) in prefixes {
+        if let Some(version) = token.strip_prefix(prefix) {
+            return Ok(vec![comparator(operator, version)?]);
+        }
+    }
+
+    if let Some(version) = token.strip_prefix('~') {
+        // Tilde allows updates within the interim release - e.g. ~17.0.3 is >=17.0.3 <17.1
+        let lower = parse_version(version)?;
+        let bump = if lower.precision() > 1 { 1 } else { 0 };
+        return Ok(vec![
+            Comparator { operator: Operator::Ge, version: lower.clone() },
+            Comparator { operator: Operator::Lt, version: bumped(&lower, bump) },
+        ]);
+    }
+
+    if let Some(version) = token.strip_prefix('^') {
+        // Caret allows anything within the feature release - e.g. ^11 is >=11 <12
+        let lower = parse_version(version)?;
+        return Ok(vec![
+            Comparator { operator: Operator::Ge, version: lower.clone() },
+            Comparator { operator: Operator::Lt, version: bumped(&lower, 0) },
+        ]);
+    }
+
+    Ok(vec![comparator(Operator::Prefix, token)?])
+}
+
+// Maven style ranges - e.g. [11,17), (11,17], [11,), (,17) or [17]
+fn parse_maven(value: &str) -> Result<Vec<Comparator>, String> {
+    let invalid = || format!("Invalid version range '{}'", value);
+    let lower_inclusive = value.starts_with('[');
+    let upper_inclusive = match value.chars().last() {
+        Some(']') => true,
+        Some(')') => false,
+        _ => return Err(invalid()),
+    };
+    let inner = &value[1..value.len() - 1];
+
+    let (lower, upper) = match inner.split_once(',') {
+        Some(bounds) => bounds,
+        None if lower_inclusive && upper_inclusive => {
+            return Ok(vec![comparator(Operator::Prefix, inner)?]);
+        }
+        None => return Err(invalid()),
+    };
+    let (lower, upper) = (lower.trim(), upper.trim());
+    if lower.is_empty() && upper.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut comparators = Vec::new();
+    if !lower.is_empty() {
+        let operator = if lower_inclusive { Operator::Ge } else { Operator::Gt };
+        comparators.push(comparator(operator, lower)?);
+    }
+    if !upper.is_empty() {
+        let operator = if upper_inclusive { Operator::Le } else { Operator::Lt };
+        comparators.push(comparator(operator, upper)?);
+    }
+    Ok(comparators)
+}
+
+fn comparator(operator: Operator, version: &str) -> Result<Comparator, String> {
+    Ok(Comparator { operator, version: parse_version(version)? })
+}
+
+fn parse_version(version: &str) -> Result<JavaVersion, String> {
+    JavaVersion::parse(version).ok_or_else(|| format!("Invalid version '{}'", version))
+}
+
+// Increments the component at the given index, dropping anything after it - e.g. 17.0.3 -> 17.1
+fn bumped(version: &JavaVersion, index: usize) -> JavaVersion {
+    let mut bumped = version.truncated(index + 1);
+    match index {
+        0 => bumped.feature += 1,
+        _ => bumped.interim += 1,
+    }
+    bumped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(range: &str, version: &str) -> bool {
+        VersionRange::parse(range).unwrap().matches(&JavaVersion::parse(version).unwrap())
+    }
+
+    #[test]
+    fn test_shorthands() {
+        assert!(matches("17", "17.0.2"));
+        assert!(!matches("17", "11.0.2"));
+        assert!(matches("1.8", "1.8.0_292"));
+        assert!(matches("11+", "17.0.2"));
+        assert!(!matches("11.0.3+", "11.0.2"));
+        assert!(matches("=17", "17.0.2"));
+    }
+
+    #[test]
+    fn test_operators() {
+        assert!(matches(">=11 <17", "11.0.2"));
+        assert!(matches(">=11 <17", "16"));
+        assert!(!matches(">=11 <17", "17.0.2"));
+        assert!(!matches(">=11 <17", "1.8.0_292"));
+        assert!(matches(">= 11, < 17", "11.0.2"));
+        assert!(matches("<=17", "17.0.9"));
+        assert!(!matches(">17", "17.0.9"));
+        assert!(matches(">17", "21"));
+        assert!(matches("!=19", "17.0.2"));
+        assert!(!matches("!=19", "19.0.1"));
+    }
+
+    #[test]
+    fn test_dot_ranges() {
+        assert!(matches("11..17", "11.0.2"));
+        assert!(!matches("11..17", "17.0.2"));
+        assert!(matches("11..=17", "17.0.2"));
+        assert!(matches("11..", "21"));
+        assert!(!matches("..11", "11.0.2"));
+    }
+
+    #[test]
+    fn test_tilde_caret() {
+        assert!(matches("~17.0.3", "17.0.3"));
+        assert!(matches("~17.0.3", "17.0.9"));
+        assert!(!matches("~17.0.3", "17.0.2"));
+        assert!(!matches("~17.0.3", "17.1"));
+        assert!(matches("~17", "17.0.9"));
+        assert!(!matches("~17", "18"));
+        assert!(matches("^11", "11.0.21"));
+        assert!(!matches("^11", "12"));
+        assert!(!matches("^11.0.5", "11.0.4"));
+    }
+
+    #[test]
+    fn test_maven() {
+        assert!(matches("[11,17)", "11.0.2"));
+        assert!(!matches("[11,17)", "17.0.2"));
+        assert!(matches("(11,17]", "17.0.2"));
+        assert!(!matches("(11,17]", "11.0.2"));
+        assert!(matches("[11,)", "21"));
+        assert!(matches("(,17)", "1.8.0_292"));
+        assert!(matches("[17]", "17.0.2"));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(VersionRange::parse("").is_err());
+        assert!(VersionRange::parse("abc").is_err());
+        assert!(VersionRange::parse(">=").is_err());
+        assert!(VersionRange::parse("[11,17").is_err());
+        assert!(VersionRange::parse("[,]").is_err());
+        assert!(VersionRange::parse("..").is_err());
+    }
+}
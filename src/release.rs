@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::pattern::NamePattern;
+
+// Release file keys kept as metadata, in the order they're shown
+const KEYS: [&str; 11] = [
+    "IMPLEMENTOR",
+    "IMPLEMENTOR_VERSION",
+    "JAVA_RUNTIME_VERSION",
+    "JAVA_VERSION_DATE",
+    "JVM_VARIANT",
+    "OS_NAME",
+    "LIBC",
+    "SOURCE",
+    "BUILD_TYPE",
+    "GRAALVM_VERSION",
+    "MODULES",
+];
+
+/// Metadata from a JVM's release file, beyond the version, vendor and architecture
+/// already used to identify it
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize)]
+pub struct Release {
+    pub implementor: Option<String>,
+    pub implementor_version: Option<String>,
+    pub java_runtime_version: Option<String>,
+    pub java_version_date: Option<String>,
+    pub jvm_variant: Option<String>,
+    pub os_name: Option<String>,
+    pub libc: Option<String>,
+    pub modules: Vec<String>,
+    pub source: Option<String>,
+    pub build_type: Option<String>,
+    pub graalvm_version: Option<String>,
+}
+
+impl Release {
+    pub fn from_properties(properties: &HashMap<String, String>) -> Release {
+        let value = |key: &str| properties.get(key)
+            .map(|value| value.replace('"', "").trim().to_string())
+            .filter(|value| !value.is_empty());
+        Release {
+            implementor: value("IMPLEMENTOR"),
+            implementor_version: value("IMPLEMENTOR_VERSION"),
+            java_runtime_version: value("JAVA_RUNTIME_VERSION"),
+            java_version_date: value("JAVA_VERSION_DATE"),
+            jvm_variant: value("JVM_VARIANT"),
+            os_name: value("OS_NAME"),
+            libc: value("LIBC"),
+            modules: value("MODULES")
+                .map(|modules| modules.split_whitespace().map(String::from).collect())
+                .unwrap_or_default(),
+            source: value("SOURCE"),
+            build_type: value("BUILD_TYPE"),
+            graalvm_version: value("GRAALVM_VERSION"),
+        }
+    }
+
+    /// Value of a release file key, with the modules space separated as in the file
+    pub fn value(&self, key: &str) -> Option<String> {
+        let value = match key {
+            "IMPLEMENTOR" => &self.implementor,
+            "IMPLEMENTOR_VERSION" => &self.implementor_version,
+            "JAVA_RUNTIME_VERSION" => &self.java_runtime_version,
+            "JAVA_VERSION_DATE" => &self.java_version_date,
+            "JVM_VARIANT" => &self.jvm_variant,
+            "OS_NAME" => &self.os_name,
+            "LIBC" => &self.libc,
+            "SOURCE" => &self.source,
+            "BUILD_TYPE" => &self.build_type,
+            "GRAALVM_VERSION" => &self.graalvm_version,
+            "MODULES" if !self.modules.is_empty() => return Some(self.modules.join(" ")),
+            _ => return None,
+        };
+        value.clone()
+    }
+
+    /// Keys and values present, in display order
+    pub fn entries(&self) -> Vec<(&'static str, String)> {
+        KEYS.iter()
+            .filter_map(|key| self.value(key).map(|value| (*key, value)))
+            .collect()
+    }
+}
+
+/// Release file filter given as KEY=PATTERN (e.g. LIBC=musl), with the pattern matched like a
+/// name filter. MODULES matches if any one module does.
+#[derive(Clone, Debug)]
+pub struct ReleaseFilter {
+    key: &'static str,
+    pattern: NamePattern,
+}
+
+impl ReleaseFilter {
+    pub fn parse(value: &str) -> Result<ReleaseFilter, String> {
+        let (key, pattern) = value.split_once('=')
+            .ok_or_else(|| format!("Invalid release filter '{}': expected KEY=PATTERN", value))?;
+        let key = KEYS.iter()
+            .find(|known| known.eq_ignore_ascii_case(key.trim()))
+            .ok_or_else(|| format!("Unknown release key '{}': expected one of {}", key, KEYS.join(", ")))?;
+        Ok(ReleaseFilter { key, pattern: NamePattern::parse(pattern)? })
+    }
+
+    pub fn matches(&self, release: &Release) -> bool {
+        if self.key == "MODULES" {
+            return release.modules.iter().any(|module| self.pattern.matches(module));
+        }
+        release.value(self.key).is_some_and(|value| self.pattern.matches(&value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temurin() -> Release {
+        let properties = HashMap::from([
+            ("IMPLEMENTOR", "\"Eclipse Adoptium\""),
+            ("IMPLEMENTOR_VERSION", "\"Temurin-17.0.9+9\""),
+            ("JAVA_VERSION", "\"17.0.9\""),
+            ("JVM_VARIANT", "\"Hotspot\""),
+            ("LIBC", "\"musl\""),
+            ("MODULES", "\"java.base java.logging jdk.compiler\""),
+            ("BUILD_TYPE", "\"\""),
+        ].map(|(key, value)| (key.to_string(), value.to_string())));
+        Release::from_properties(&properties)
+    }
+
+    #[test]
+    fn test_from_properties() {
+        let release = temurin();
+        assert_eq!(release.implementor.as_deref(), Some("Eclipse Adoptium"));
+        assert_eq!(release.libc.as_deref(), Some("musl"));
+        assert_eq!(release.modules, vec!["java.base", "java.logging", "jdk.compiler"]);
+        assert_eq!(release.build_type, None);
+        assert_eq!(release.entries(), vec![
+            ("IMPLEMENTOR", "Eclipse Adoptium".to_string()),
+            ("IMPLEMENTOR_VERSION", "Temurin-17.0.9+9".to_string()),
+            ("JVM_VARIANT", "Hotspot".to_string()),
+            ("LIBC", "musl".to_string()),
+            ("MODULES", "java.base java.logging jdk.compiler".to_string()),
+        ]);
+        assert_eq!(Release::from_properties(&HashMap::new()), Release::default());
+    }
+
+    #[test]
+    fn test_filter() {
+        let matches = |filter: &str| ReleaseFilter::parse(filter).unwrap().matches(&temurin());
+        assert!(matches("LIBC=musl"));
+        assert!(matches("jvm_variant=hotspot"));
+        assert!(matches("IMPLEMENTOR=*Adoptium"));
+        assert!(matches("MODULES=jdk.compiler"));
+        assert!(!matches("MODULES=java.desktop"));
+        assert!(!matches("LIBC=glibc"));
+        assert!(!matches("GRAALVM_VERSION=22"));
+        assert!(ReleaseFilter::parse("LIBC").is_err());
+        assert!(ReleaseFilter::parse("JAVA_HOME=x").is_err());
+        assert!(ReleaseFilter::parse("SOURCE=re:(").is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt;
+use std::path::Path;
+
+/// Something that couldn't be read while scanning, and why
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScanIssue {
+    pub path: String,
+    pub reason: String,
+}
+
+impl fmt::Display for ScanIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.path, self.reason)
+    }
+}
+
+/// Problems found while scanning, so a bad location or entry is skipped rather than ending the scan
+#[derive(Debug, Default)]
+pub struct ScanReport {
+    /// Locations that couldn't be searched at all - e.g. a registered directory that was deleted
+    pub locations: Vec<ScanIssue>,
+    /// Individual entries that were skipped - e.g. dangling symlinks or unreadable release files
+    pub entries: Vec<ScanIssue>,
+}
+
+impl ScanReport {
+    pub fn location(&mut self, path: impl AsRef<Path>, reason: impl fmt::Display) {
+        self.locations.push(issue(path, reason));
+    }
+
+    pub fn entry(&mut self, path: impl AsRef<Path>, reason: impl fmt::Display) {
+        self.entries.push(issue(path, reason));
+    }
+
+    /// Lines for stderr - skipped locations are always worth a warning, skipped entries only when verbose
+    pub fn messages(&self, verbose: bool) -> Vec<String> {
+        let mut messages: Vec<String> = self.locations.iter()
+            .map(|issue| format!("Warning: skipped location {}", issue))
+            .collect();
+        if verbose && !self.entries.is_empty() {
+            messages.push("Diagnostics:".to_string());
+            for issue in &self.entries {
+                messages.push(format!("  skipped {}", issue));
+            }
+        }
+        messages
+    }
+}
+
+fn issue(path: impl AsRef<Path>, reason: impl fmt::Display) -> ScanIssue {
+    ScanIssue {
+        path: path.as_ref().to_string_lossy().to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages() {
+        let mut report = ScanReport::default();
+        assert!(report.messages(true).is_empty());
+
+        report.location("/opt/jvms", "No such file or directory");
+        report.entry("/usr/lib/jvm/default-java", "dangling symlink");
+        assert_eq!(report.messages(false),
+                   vec!["Warning: skipped location /opt/jvms (No such file or directory)"]);
+        assert_eq!(report.messages(true),
+                   vec!["Warning: skipped location /opt/jvms (No such file or directory)",
+                        "Diagnostics:",
+                        "  skipped /usr/lib/jvm/default-java (dangling symlink)"]);
+    }
+}
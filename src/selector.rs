@@ -0,0 +1,101 @@
+use crate::range::VersionRange;
+use crate::version::JavaVersion;
+
+// Feature releases designated as Long Term Support
+const LTS_RELEASES: [u32; 5] = [8, 11, 17, 21, 25];
+// After the last known LTS, one is expected every fourth feature release (two year cadence)
+const LTS_CADENCE: u32 = 4;
+
+pub fn is_lts(feature: u32) -> bool {
+    let last_known = LTS_RELEASES[LTS_RELEASES.len() - 1];
+    if feature > last_known {
+        return (feature - last_known).is_multiple_of(LTS_CADENCE);
+    }
+    LTS_RELEASES.contains(&feature)
+}
+
+/// What to pick from the discovered JVMs based on the --version argument
+#[derive(Clone, Debug)]
+pub enum VersionSelector {
+    /// Plain version or range expression - e.g. 17, 11+, ">=11 <17"
+    Range(VersionRange),
+    /// Only LTS releases
+    Lts,
+    /// Newest installed LTS release or anything newer
+    LtsOrNewer,
+    /// Single newest JVM
+    Latest,
+    /// Single oldest JVM
+    Oldest,
+    /// Single newest JVM of the given release - e.g. "latest-patch-of 17"
+    LatestPatchOf(JavaVersion),
+}
+
+impl VersionSelector {
+    pub fn parse(value: &str) -> Result<VersionSelector, String> {
+        let keyword = value.trim().to_ascii_lowercase();
+        match keyword.as_str() {
+            "lts" => return Ok(VersionSelector::Lts),
+            "lts+" => return Ok(VersionSelector::LtsOrNewer),
+            "latest" => return Ok(VersionSelector::Latest),
+            "oldest" => return Ok(VersionSelector::Oldest),
+            _ => {}
+        }
+        if let Some(version) = keyword.strip_prefix("latest-patch-of") {
+            let version = version.trim_start_matches([' ', '=', ':']);
+            return JavaVersion::parse(version)
+                .map(VersionSelector::LatestPatchOf)
+                .ok_or_else(|| format!("Invalid version '{}'", version));
+        }
+        VersionRange::parse(value).map(VersionSelector::Range)
+    }
+
+    /// Whether a single version is eligible, before any picking across the whole set
+    pub fn matches(&self, version: &JavaVersion) -> bool {
+        match self {
+            VersionSelector::Range(range) => range.matches(version),
+            VersionSelector::Lts => is_lts(version.feature),
+            VersionSelector::LatestPatchOf(release) => version.cmp_prefix(release).is_eq(),
+            VersionSelector::LtsOrNewer | VersionSelector::Latest | VersionSelector::Oldest => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_lts() {
+        assert!(is_lts(8));
+        assert!(is_lts(17));
+        assert!(is_lts(21));
+        assert!(is_lts(25));
+        assert!(is_lts(29));
+        assert!(!is_lts(9));
+        assert!(!is_lts(22));
+        assert!(!is_lts(27));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(matches!(VersionSelector::parse("lts"), Ok(VersionSelector::Lts)));
+        assert!(matches!(VersionSelector::parse("LTS+"), Ok(VersionSelector::LtsOrNewer)));
+        assert!(matches!(VersionSelector::parse("latest"), Ok(VersionSelector::Latest)));
+        assert!(matches!(VersionSelector::parse("oldest"), Ok(VersionSelector::Oldest)));
+        assert!(matches!(VersionSelector::parse("latest-patch-of 17"), Ok(VersionSelector::LatestPatchOf(_))));
+        assert!(matches!(VersionSelector::parse("latest-patch-of=1.8"), Ok(VersionSelector::LatestPatchOf(_))));
+        assert!(matches!(VersionSelector::parse(">=11 <17"), Ok(VersionSelector::Range(_))));
+        assert!(VersionSelector::parse("latest-patch-of").is_err());
+        assert!(VersionSelector::parse("newest").is_err());
+    }
+
+    #[test]
+    fn test_matches() {
+        let version = JavaVersion::parse("17.0.2").unwrap();
+        assert!(VersionSelector::Lts.matches(&version));
+        assert!(!VersionSelector::Lts.matches(&JavaVersion::parse("19.0.1").unwrap()));
+        assert!(VersionSelector::parse("latest-patch-of 17").unwrap().matches(&version));
+        assert!(!VersionSelector::parse("latest-patch-of 11").unwrap().matches(&version));
+    }
+}
@@ -0,0 +1,685 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::arch::Architecture;
+use crate::candidate::{java_version, Candidate};
+use crate::report::ScanReport;
+use crate::toolchains::user_toolchains;
+use crate::vendor::Vendor;
+use crate::version::JavaVersion;
+
+const NIX_STORE: &str = "/nix/store";
+
+// Where IDEs are installed by hand, and as snaps
+const IDE_DIRS: [&str; 2] = ["/opt", "/Applications"];
+const SNAP_DIR: &str = "/snap";
+
+// Homebrew's default prefix on Linux
+const LINUXBREW_PREFIX: &str = "/home/linuxbrew/.linuxbrew";
+
+// Data directory variable and default of tools using the asdf-java plugin layout
+const PLUGIN_TOOLS: [(&str, &str); 3] = [
+    ("ASDF_DATA_DIR", ".asdf"),
+    ("MISE_DATA_DIR", ".local/share/mise"),
+    ("RTX_DATA_DIR", ".local/share/rtx"),
+];
+
+/// Installs from every per-user tool found for the current user
+pub fn user_candidates(report: &mut ScanReport) -> Vec<Candidate> {
+    let mut candidates = vec![];
+    if let Some(dir) = tool_dir("SDKMAN_DIR", ".sdkman") {
+        candidates.append(&mut sdkman(&dir.join("candidates/java"), report));
+    }
+    // mise was previously called rtx, and both use the asdf-java plugin naming
+    for (variable, default) in PLUGIN_TOOLS {
+        if let Some(dir) = tool_dir(variable, default) {
+            candidates.append(&mut plugin_installs(&dir.join("installs/java"), report));
+        }
+    }
+    if let Some(dir) = tool_dir("JENV_ROOT", ".jenv") {
+        candidates.append(&mut jenv(&dir, report));
+    }
+    if let Some(dir) = tool_dir("JABBA_HOME", ".jabba") {
+        candidates.append(&mut jabba(&dir.join("jdk"), report));
+    }
+    if let Some(dir) = tool_dir("GRADLE_USER_HOME", ".gradle") {
+        candidates.append(&mut gradle(&dir.join("jdks"), report));
+    }
+    if let Some(dir) = home_dir(".jdks") {
+        candidates.append(&mut intellij(&dir, report));
+    }
+    candidates.append(&mut user_toolchains(report));
+    candidates
+}
+
+/// SDKMAN! installs - one directory per identifier (e.g. 17.0.9-tem), plus a "current" symlink
+pub fn sdkman(dir: &Path, report: &mut ScanReport) -> Vec<Candidate> {
+    // The current symlink points at one of the installs rather than being an install itself
+    let current = fs::read_link(dir.join("current"))
+        .ok()
+        .and_then(|target| target.file_name().map(|name| name.to_string_lossy().to_string()));
+
+    read_tool_dir(dir, report).into_iter()
+        .filter(|path| path.file_name().is_some_and(|name| name != "current"))
+        .map(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let (version, vendor) = parse_sdkman_identifier(&name);
+            let mut tags = vec![];
+            if current.as_ref() == Some(&name) {
+                tags.push("sdkman current".to_string());
+            }
+            Candidate { path, name, version, vendor, tags, ..Default::default() }
+        })
+        .collect()
+}
+
+/// Splits an SDKMAN! identifier such as 17.0.9-tem, 21.fx-zulu or 22.ea.20-open into a Java version
+/// and vendor, leaving the version empty when it can't be worked out
+pub fn parse_sdkman_identifier(identifier: &str) -> (String, Vendor) {
+    let (version, suffix) = match identifier.rsplit_once('-') {
+        Some(parts) => parts,
+        None => return (String::new(), Vendor::Unknown),
+    };
+    let version = version.trim_end_matches(".fx").trim_end_matches(".crac");
+    let version = if let Some((feature, build)) = version.split_once(".ea.") {
+        format!("{}-ea+{}", feature, build)
+    } else if let Some((_, feature)) = version.split_once(".r") {
+        // Older GraalVM identifiers carry the GraalVM version, e.g. 22.3.r17-grl is Java 17
+        feature.to_string()
+    } else {
+        version.to_string()
+    };
+    (java_version(&version), sdkman_vendor(suffix))
+}
+
+fn sdkman_vendor(suffix: &str) -> Vendor {
+    match suffix {
+        "tem" => Vendor::Temurin,
+        "zulu" => Vendor::Zulu,
+        "amzn" => Vendor::Corretto,
+        "librca" | "nik" => Vendor::Liberica,
+        "graal" | "graalce" | "grl" => Vendor::GraalVm,
+        "sem" => Vendor::Semeru,
+        "ms" => Vendor::Microsoft,
+        // jdk.java.net builds are published by Oracle
+        "oracle" | "open" => Vendor::Oracle,
+        "sapmchn" => Vendor::SapMachine,
+        "albba" => Vendor::Dragonwell,
+        "jbr" => Vendor::JetBrains,
+        "kona" => Vendor::Other("Tencent".to_string()),
+        "mandrel" => Vendor::Other("Mandrel".to_string()),
+        _ => Vendor::Unknown,
+    }
+}
+
+/// asdf and mise installs - one directory per install name, skipping the alias symlinks mise adds
+/// (e.g. temurin-17 pointing at temurin-17.0.9+9)
+pub fn plugin_installs(dir: &Path, report: &mut ScanReport) -> Vec<Candidate> {
+    read_tool_dir(dir, report).into_iter()
+        .filter(|path| fs::symlink_metadata(path).is_ok_and(|metadata| !metadata.file_type().is_symlink()))
+        .map(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let (version, vendor) = parse_plugin_name(&name);
+            Candidate { path, name, version, vendor, ..Default::default() }
+        })
+        .collect()
+}
+
+/// Splits an asdf-java style install name such as temurin-17.0.9+9, corretto-21.0.1.12.1 or (mise's
+/// OpenJDK builds) 21.0.1 into a Java version and vendor
+pub fn parse_plugin_name(name: &str) -> (String, Vendor) {
+    // The distribution runs up to the first dash followed by a digit
+    let start = name.char_indices()
+        .find(|(i, c)| c.is_ascii_digit() && (*i == 0 || name[..*i].ends_with('-')))
+        .map(|(i, _)| i);
+    let (distribution, version) = match start {
+        Some(0) => ("openjdk", name),
+        // jenv adds the word size, e.g. openjdk64-17.0.9
+        Some(i) => (name[..i - 1].trim_end_matches("64"), &name[i..]),
+        None => return (String::new(), Vendor::Unknown),
+    };
+    let vendor = match distribution {
+        // The same jdk.java.net builds as SDKMAN's "open"
+        "openjdk" => Vendor::Oracle,
+        "jbr" => Vendor::JetBrains,
+        // Spelt as SDKMAN does, so Mandrel shows the same whichever tool installed it
+        "mandrel" => Vendor::Other("Mandrel".to_string()),
+        distribution => Vendor::detect(distribution, "", ""),
+    };
+    // Mandrel names only carry the Mandrel version (e.g. 23.1 is Java 21), so the release file has to supply it
+    if distribution == "mandrel" {
+        return (String::new(), vendor);
+    }
+
+    // Semeru names also carry the OpenJ9 version, e.g. 17.0.9+9_openj9-0.41.0
+    let version = version.split('_').next().unwrap_or_default();
+    // Older GraalVM names carry the GraalVM version, e.g. 22.3.0+java17 is Java 17
+    let version = version.split_once("+java").map_or(version, |(_, feature)| feature);
+    // Zulu names carry the Zulu version, which only shares the feature release with Java
+    let version = match vendor {
+        Vendor::Zulu => version.split('.').next().unwrap_or_default(),
+        _ => version,
+    };
+    // Java 8 names may use the update form, e.g. 8u392+9 is 8.0.392+9
+    let version = match version.split_once('u') {
+        Some((feature, update)) if feature.chars().all(|c| c.is_ascii_digit()) => {
+            format!("{}.0.{}", feature, update)
+        }
+        _ => version.to_string(),
+    };
+    (java_version(&version), vendor)
+}
+
+/// jenv installs - the versions directory holds links to JVM homes installed elsewhere, usually
+/// several per home (e.g. 17, 17.0 and openjdk64-17.0.9), so each home is returned once under its
+/// longest name. The global version set with `jenv global` is tagged.
+pub fn jenv(dir: &Path, report: &mut ScanReport) -> Vec<Candidate> {
+    let global = fs::read_to_string(dir.join("version"))
+        .map(|version| version.trim().to_string())
+        .unwrap_or_default();
+
+    let mut homes: HashMap<PathBuf, Candidate> = HashMap::new();
+    for link in read_tool_dir(&dir.join("versions"), report) {
+        let path = match fs::canonicalize(&link) {
+            Ok(path) => path,
+            Err(error) => {
+                report.entry(&link, error);
+                continue;
+            }
+        };
+        let name = link.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let candidate = homes.entry(path.clone()).or_insert_with(|| Candidate { path, ..Default::default() });
+        if name == global {
+            candidate.tags.push("jenv global".to_string());
+        }
+        // jenv names follow the same shape as asdf-java, with the word size after the distribution
+        if name.len() > candidate.name.len() {
+            let (version, vendor) = parse_plugin_name(&name);
+            candidate.version = version;
+            // jenv calls every OpenJDK based build openjdk64, whoever built it
+            candidate.vendor = if name.starts_with("openjdk") { Vendor::Unknown } else { vendor };
+            candidate.name = name;
+        }
+    }
+    let mut candidates: Vec<Candidate> = homes.into_values().collect();
+    candidates.sort_by(|a, b| a.path.cmp(&b.path));
+    candidates
+}
+
+/// jabba installs - one directory per <distribution>@<version> (e.g. zulu@1.17.0), holding a macOS
+/// bundle on macOS
+pub fn jabba(dir: &Path, report: &mut ScanReport) -> Vec<Candidate> {
+    read_tool_dir(dir, report).into_iter()
+        .map(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let (version, vendor) = parse_jabba_name(&name);
+            Candidate { path: bundle_home(path), name, version, vendor, ..Default::default() }
+        })
+        .collect()
+}
+
+/// Splits a jabba name such as zulu@1.17.0 or amazon-corretto@1.11.0-9.1 into a Java version and
+/// vendor - jabba writes every version in the legacy 1.x form
+pub fn parse_jabba_name(name: &str) -> (String, Vendor) {
+    let (distribution, version) = match name.split_once('@') {
+        Some(parts) => parts,
+        None => return (String::new(), Vendor::Unknown),
+    };
+    let vendor = match distribution {
+        "adopt" | "adopt-openj9" => Vendor::Temurin,
+        "openjdk" | "openjdk-ri" => Vendor::Oracle,
+        "sap" => Vendor::SapMachine,
+        "system" => Vendor::Unknown,
+        distribution => Vendor::detect(distribution, "", ""),
+    };
+    // Only Java 8 and older really use the 1.x form, and the update follows a dash
+    // - e.g. 1.11.0-11 is 11.0.11 and 1.8.0-292 is 1.8.0_292
+    let legacy = match JavaVersion::parse(version) {
+        Some(parsed) => parsed.legacy && parsed.feature <= 8,
+        None => false,
+    };
+    let version = if legacy {
+        version.replacen('-', "_", 1)
+    } else {
+        version.strip_prefix("1.").unwrap_or(version).replacen('-', ".", 1)
+    };
+    (java_version(&version), vendor)
+}
+
+/// Gradle toolchain downloads - one directory per vendor, version, architecture and OS
+/// (e.g. eclipse_adoptium-17-amd64-linux), alongside the downloaded archives and lock files
+pub fn gradle(dir: &Path, report: &mut ScanReport) -> Vec<Candidate> {
+    read_tool_dir(dir, report).into_iter()
+        .filter(|path| path.is_dir())
+        .filter_map(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let (version, vendor, architecture) = parse_gradle_name(&name);
+            let path = gradle_home(&path)?;
+            Some(Candidate { path, name, version, vendor, architecture, ..Default::default() })
+        })
+        .collect()
+}
+
+/// Splits a Gradle toolchain directory name such as eclipse_adoptium-17-amd64-linux or
+/// azul_systems__inc_-11-aarch64-mac_os_x.2 into the feature version, vendor and architecture
+pub fn parse_gradle_name(name: &str) -> (String, Vendor, Architecture) {
+    let parts: Vec<&str> = name.split('-').collect();
+    if parts.len() < 3 {
+        return (String::new(), Vendor::Unknown, Architecture::Unknown);
+    }
+    let vendor = Vendor::detect(&parts[0].replace('_', " "), "", "");
+    (java_version(parts[1]), vendor, Architecture::parse(parts[2]))
+}
+
+// Older Gradle versions unpack the archive as-is, so the home can be the archive's top level
+// directory (e.g. jdk-17.0.9+9) rather than the install directory itself
+fn gradle_home(dir: &Path) -> Option<PathBuf> {
+    let is_home = |path: &PathBuf| path.join("bin").is_dir();
+    let home = bundle_home(dir.to_path_buf());
+    if is_home(&home) {
+        return Some(home);
+    }
+    fs::read_dir(dir).ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| bundle_home(entry.path()))
+        .find(is_home)
+}
+
+/// IntelliJ IDEA downloads - one directory per install name (e.g. corretto-17.0.9), named the same
+/// way as asdf-java
+pub fn intellij(dir: &Path, report: &mut ScanReport) -> Vec<Candidate> {
+    read_tool_dir(dir, report).into_iter()
+        .filter(|path| path.is_dir())
+        .map(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let (version, vendor) = parse_plugin_name(&name);
+            Candidate { path: bundle_home(path), name, version, vendor, ..Default::default() }
+        })
+        .collect()
+}
+
+// On macOS the JVM home sits inside a bundle
+fn bundle_home(path: PathBuf) -> PathBuf {
+    let home = path.join("Contents/Home");
+    if home.is_dir() {
+        home
+    } else {
+        path
+    }
+}
+
+/// JVMs in the Nix store - nixpkgs puts the home inside the store path
+/// (e.g. <hash>-openjdk-17.0.9+9/lib/openjdk), and the one in the user's profile is tagged
+pub fn nix(report: &mut ScanReport) -> Vec<Candidate> {
+    let profile = home_dir(".nix-profile/lib/openjdk");
+    nix_store(Path::new(NIX_STORE), profile.as_deref(), report)
+}
+
+pub fn nix_store(store: &Path, profile: Option<&Path>, report: &mut ScanReport) -> Vec<Candidate> {
+    let mut candidates: Vec<Candidate> = read_tool_dir(store, report).into_iter()
+        .filter_map(|path| {
+            let home = nix_home(&path)?;
+            // Store paths are named <hash>-<package>-<version>
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let name = name.split_once('-').map_or(name.as_str(), |(_, name)| name).to_string();
+            let (version, vendor) = parse_plugin_name(&name);
+            // nixpkgs builds OpenJDK from source, so it isn't anyone else's build
+            let vendor = if name.starts_with("openjdk") { Vendor::Unknown } else { vendor };
+            Some(Candidate { path: home, name, version, vendor, ..Default::default() })
+        })
+        .collect();
+    if let Some(home) = profile.and_then(|profile| fs::canonicalize(profile).ok()) {
+        let name = home.parent().and_then(|lib| lib.parent()).and_then(|path| path.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        candidates.push(Candidate { path: home, name, tags: vec!["nix profile".to_string()], ..Default::default() });
+    }
+    candidates
+}
+
+// The JVM home inside a store path, if the package is a JVM
+fn nix_home(path: &Path) -> Option<PathBuf> {
+    let nested = path.join("lib/openjdk");
+    if nested.is_dir() {
+        return Some(nested);
+    }
+    // Binary distributions such as temurin-bin are unpacked as-is
+    Some(path.to_path_buf()).filter(|path| path.join("release").is_file() && path.join("bin/java").is_file())
+}
+
+/// JVMs installed with Homebrew on Linux - each openjdk formula is linked from opt to its keg in
+/// the Cellar, with the home under libexec (e.g. opt/openjdk@17 -> Cellar/openjdk@17/17.0.9/libexec)
+pub fn linuxbrew(report: &mut ScanReport) -> Vec<Candidate> {
+    let prefix = env::var_os("HOMEBREW_PREFIX")
+        .filter(|prefix| !prefix.is_empty())
+        .map_or_else(|| PathBuf::from(LINUXBREW_PREFIX), PathBuf::from);
+    linuxbrew_formulae(&prefix.join("opt"), report)
+}
+
+pub fn linuxbrew_formulae(opt: &Path, report: &mut ScanReport) -> Vec<Candidate> {
+    read_tool_dir(opt, report).into_iter()
+        .filter_map(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            if name != "openjdk" && !name.starts_with("openjdk@") {
+                return None;
+            }
+            let home = fs::canonicalize(path.join("libexec")).ok()?;
+            // The keg is named after the formula version, e.g. 17.0.9 or 21.0.1_1 for a rebuild
+            let keg = home.parent()?.file_name()?.to_string_lossy().to_string();
+            let version = java_version(keg.split('_').next().unwrap_or_default());
+            Some(Candidate { path: home, name, version, ..Default::default() })
+        })
+        .collect()
+}
+
+/// Runtimes bundled with JetBrains IDEs - the JetBrains Runtime under each IDE's jbr directory (jre
+/// in older Android Studio releases), for IDEs installed by hand, as snaps or by the Toolbox App
+pub fn jetbrains(report: &mut ScanReport) -> Vec<Candidate> {
+    let mut ides: Vec<(String, PathBuf)> = vec![];
+    for dir in IDE_DIRS {
+        ides.extend(read_tool_dir(Path::new(dir), report).into_iter().map(|ide| (file_name(&ide), ide)));
+    }
+    for snap in read_tool_dir(Path::new(SNAP_DIR), report) {
+        ides.push((file_name(&snap), snap.join("current")));
+    }
+    if let Some(apps) = home_dir(".local/share/JetBrains/Toolbox/apps") {
+        ides.append(&mut toolbox_ides(&apps, report));
+    }
+    ide_runtimes(ides)
+}
+
+/// IDEs installed by the Toolbox App - directly in each app's directory since Toolbox 2, and by
+/// channel and build before that (e.g. IDEA-U/ch-0/233.11799.241)
+pub fn toolbox_ides(apps: &Path, report: &mut ScanReport) -> Vec<(String, PathBuf)> {
+    let mut ides = vec![];
+    for app in read_tool_dir(apps, report) {
+        let channels: Vec<PathBuf> = read_tool_dir(&app, report).into_iter()
+            .filter(|path| file_name(path).starts_with("ch-"))
+            .collect();
+        if channels.is_empty() {
+            ides.push((file_name(&app), app));
+            continue;
+        }
+        for build in channels.iter().flat_map(|channel| read_tool_dir(channel, report)) {
+            ides.push((format!("{} {}", file_name(&app), file_name(&build)), build));
+        }
+    }
+    ides
+}
+
+/// The bundled runtime of each named IDE directory, skipping directories without one
+pub fn ide_runtimes(ides: Vec<(String, PathBuf)>) -> Vec<Candidate> {
+    ides.into_iter()
+        .filter_map(|(name, ide)| {
+            // macOS apps keep everything under Contents
+            let contents = ide.join("Contents");
+            let ide = if contents.is_dir() { contents } else { ide };
+            let runtime = ["jbr", "jre"].iter().map(|dir| ide.join(dir)).find(|dir| dir.is_dir())?;
+            Some(Candidate { path: bundle_home(runtime), name, ..Default::default() })
+        })
+        .collect()
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().unwrap_or_default().to_string_lossy().to_string()
+}
+
+// A tool's data directory, from its environment variable or its default under $HOME
+fn tool_dir(variable: &str, default: &str) -> Option<PathBuf> {
+    env::var_os(variable)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| home_dir(default))
+}
+
+// A directory under $HOME
+fn home_dir(path: &str) -> Option<PathBuf> {
+    env::var_os("HOME").map(|home| Path::new(&home).join(path))
+}
+
+// Entries of a tool directory - a tool that isn't installed is not worth a warning
+fn read_tool_dir(dir: &Path, report: &mut ScanReport) -> Vec<PathBuf> {
+    match fs::read_dir(dir) {
+        Ok(entries) => {
+            let mut paths: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .collect();
+            paths.sort();
+            paths
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => vec![],
+        Err(error) => {
+            report.location(dir, error);
+            vec![]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::TempDir;
+
+    #[test]
+    fn test_parse_sdkman_identifier() {
+        assert_eq!(parse_sdkman_identifier("17.0.9-tem"), ("17.0.9".to_string(), Vendor::Temurin));
+        assert_eq!(parse_sdkman_identifier("11.0.21-amzn"), ("11.0.21".to_string(), Vendor::Corretto));
+        assert_eq!(parse_sdkman_identifier("21.fx-zulu"), ("21".to_string(), Vendor::Zulu));
+        assert_eq!(parse_sdkman_identifier("17.0.9.crac-zulu"), ("17.0.9".to_string(), Vendor::Zulu));
+        assert_eq!(parse_sdkman_identifier("22.ea.20-open"), ("22-ea+20".to_string(), Vendor::Oracle));
+        assert_eq!(parse_sdkman_identifier("22.3.r17-grl"), ("17".to_string(), Vendor::GraalVm));
+        assert_eq!(parse_sdkman_identifier("8.0.392-librca"), ("8.0.392".to_string(), Vendor::Liberica));
+        assert_eq!(parse_sdkman_identifier("21.0.1-kona"), ("21.0.1".to_string(), Vendor::Other("Tencent".to_string())));
+        assert_eq!(parse_sdkman_identifier("17.0.9-unknown"), ("17.0.9".to_string(), Vendor::Unknown));
+        assert_eq!(parse_sdkman_identifier("local-build"), (String::new(), Vendor::Unknown));
+        assert_eq!(parse_sdkman_identifier("current"), (String::new(), Vendor::Unknown));
+    }
+
+    #[test]
+    fn test_parse_plugin_name() {
+        assert_eq!(parse_plugin_name("temurin-17.0.9+9"), ("17.0.9+9".to_string(), Vendor::Temurin));
+        assert_eq!(parse_plugin_name("temurin-jre-17.0.9+9"), ("17.0.9+9".to_string(), Vendor::Temurin));
+        assert_eq!(parse_plugin_name("corretto-21.0.1.12.1"), ("21.0.1.12.1".to_string(), Vendor::Corretto));
+        assert_eq!(parse_plugin_name("adoptopenjdk-openj9-11.0.11+9"), ("11.0.11+9".to_string(), Vendor::Temurin));
+        assert_eq!(parse_plugin_name("semeru-openj9-17.0.9+9_openj9-0.41.0"), ("17.0.9+9".to_string(), Vendor::Semeru));
+        assert_eq!(parse_plugin_name("zulu-17.44.53"), ("17".to_string(), Vendor::Zulu));
+        assert_eq!(parse_plugin_name("graalvm-22.3.0+java17"), ("17".to_string(), Vendor::GraalVm));
+        assert_eq!(parse_plugin_name("oracle-graalvm-21.0.1"), ("21.0.1".to_string(), Vendor::GraalVm));
+        assert_eq!(parse_plugin_name("liberica-javafx-8u392+9"), ("8.0.392+9".to_string(), Vendor::Liberica));
+        assert_eq!(parse_plugin_name("openjdk-21"), ("21".to_string(), Vendor::Oracle));
+        assert_eq!(parse_plugin_name("21.0.1"), ("21.0.1".to_string(), Vendor::Oracle));
+        assert_eq!(parse_plugin_name("mandrel-23.1.1.0-Final"), (String::new(), Vendor::Other("Mandrel".to_string())));
+        assert_eq!(parse_plugin_name("system"), (String::new(), Vendor::Unknown));
+    }
+
+    #[test]
+    fn test_plugin_installs() {
+        let dir = TempDir::new("mise");
+        fs::create_dir_all(dir.join("temurin-17.0.9+9")).unwrap();
+        fs::create_dir_all(dir.join("corretto-21.0.1.12.1")).unwrap();
+        std::os::unix::fs::symlink("temurin-17.0.9+9", dir.join("temurin-17")).unwrap();
+
+        let mut report = ScanReport::default();
+        let candidates = plugin_installs(&dir, &mut report);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].name, "corretto-21.0.1.12.1");
+        assert_eq!(candidates[0].vendor, Vendor::Corretto);
+        assert_eq!(candidates[1].path, dir.join("temurin-17.0.9+9"));
+        assert_eq!(candidates[1].version, "17.0.9+9");
+    }
+
+    #[test]
+    fn test_jenv() {
+        let dir = TempDir::new("jenv");
+        let home = dir.join("homes/java-17-openjdk-amd64");
+        let corretto = dir.join("homes/amazon-corretto-11");
+        fs::create_dir_all(&home).unwrap();
+        fs::create_dir_all(&corretto).unwrap();
+        fs::create_dir_all(dir.join("versions")).unwrap();
+        for alias in ["17", "17.0", "openjdk64-17.0.9"] {
+            std::os::unix::fs::symlink(&home, dir.join("versions").join(alias)).unwrap();
+        }
+        std::os::unix::fs::symlink(&corretto, dir.join("versions/corretto64-11.0.21.9.1")).unwrap();
+        std::os::unix::fs::symlink(dir.join("homes/removed"), dir.join("versions/11")).unwrap();
+        fs::write(dir.join("version"), "17\n").unwrap();
+
+        let mut report = ScanReport::default();
+        let candidates = jenv(&dir, &mut report);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].name, "corretto64-11.0.21.9.1");
+        assert_eq!(candidates[0].vendor, Vendor::Corretto);
+        assert_eq!(candidates[1].path, fs::canonicalize(&home).unwrap());
+        assert_eq!(candidates[1].name, "openjdk64-17.0.9");
+        assert_eq!(candidates[1].version, "17.0.9");
+        // Any OpenJDK build, so the release file has to say whose
+        assert_eq!(candidates[1].vendor, Vendor::Unknown);
+        assert_eq!(candidates[1].tags, vec!["jenv global"]);
+        assert_eq!(report.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_jabba_name() {
+        assert_eq!(parse_jabba_name("zulu@1.17.0"), ("17.0".to_string(), Vendor::Zulu));
+        assert_eq!(parse_jabba_name("adopt@1.11.0-11"), ("11.0.11".to_string(), Vendor::Temurin));
+        assert_eq!(parse_jabba_name("amazon-corretto@1.11.0-9.1"), ("11.0.9.1".to_string(), Vendor::Corretto));
+        assert_eq!(parse_jabba_name("openjdk@1.21.0"), ("21.0".to_string(), Vendor::Oracle));
+        assert_eq!(parse_jabba_name("adopt@1.8.0-292"), ("1.8.0_292".to_string(), Vendor::Temurin));
+        assert_eq!(parse_jabba_name("graalvm@20.1.0"), ("20.1.0".to_string(), Vendor::GraalVm));
+        assert_eq!(parse_jabba_name("temurin"), (String::new(), Vendor::Unknown));
+    }
+
+    #[test]
+    fn test_parse_gradle_name() {
+        assert_eq!(parse_gradle_name("eclipse_adoptium-17-amd64-linux"),
+                   ("17".to_string(), Vendor::Temurin, Architecture::X86_64));
+        assert_eq!(parse_gradle_name("azul_systems__inc_-11-aarch64-mac_os_x.2"),
+                   ("11".to_string(), Vendor::Zulu, Architecture::Aarch64));
+        assert_eq!(parse_gradle_name("amazon_com_inc_-21-x86_64-linux"),
+                   ("21".to_string(), Vendor::Corretto, Architecture::X86_64));
+        assert_eq!(parse_gradle_name("jdk-17.0.9+9"), (String::new(), Vendor::Unknown, Architecture::Unknown));
+    }
+
+    #[test]
+    fn test_gradle() {
+        let dir = TempDir::new("gradle");
+        // Unpacked as-is by older Gradle versions, and directly into the install directory by newer ones
+        fs::create_dir_all(dir.join("eclipse_adoptium-17-amd64-linux/jdk-17.0.9+9/bin")).unwrap();
+        fs::create_dir_all(dir.join("eclipse_adoptium-21-amd64-linux/bin")).unwrap();
+        fs::create_dir_all(dir.join("eclipse_adoptium-11-amd64-linux")).unwrap();
+        fs::write(dir.join("eclipse_adoptium-17-amd64-linux.lock"), "").unwrap();
+
+        let mut report = ScanReport::default();
+        let candidates = gradle(&dir, &mut report);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].path, dir.join("eclipse_adoptium-17-amd64-linux/jdk-17.0.9+9"));
+        assert_eq!(candidates[0].version, "17");
+        assert_eq!(candidates[0].architecture, Architecture::X86_64);
+        assert_eq!(candidates[1].path, dir.join("eclipse_adoptium-21-amd64-linux"));
+    }
+
+    #[test]
+    fn test_intellij() {
+        let dir = TempDir::new("intellij");
+        fs::create_dir_all(dir.join("corretto-17.0.9")).unwrap();
+        fs::create_dir_all(dir.join("jbr-17.0.9/Contents/Home")).unwrap();
+        fs::write(dir.join("corretto-17.0.9.intellij"), "").unwrap();
+
+        let mut report = ScanReport::default();
+        let candidates = intellij(&dir, &mut report);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].vendor, Vendor::Corretto);
+        assert_eq!(candidates[1].path, dir.join("jbr-17.0.9/Contents/Home"));
+        assert_eq!(candidates[1].vendor, Vendor::JetBrains);
+    }
+
+    #[test]
+    fn test_nix_store() {
+        let dir = TempDir::new("nix");
+        let store = dir.join("store");
+        fs::create_dir_all(store.join("0c5x-openjdk-17.0.9+9/lib/openjdk/bin")).unwrap();
+        fs::create_dir_all(store.join("1d6y-temurin-bin-21.0.1/bin")).unwrap();
+        fs::write(store.join("1d6y-temurin-bin-21.0.1/bin/java"), "").unwrap();
+        fs::write(store.join("1d6y-temurin-bin-21.0.1/release"), "").unwrap();
+        fs::create_dir_all(store.join("2e7z-glibc-2.38/lib")).unwrap();
+        fs::create_dir_all(dir.join("profile/lib")).unwrap();
+        std::os::unix::fs::symlink(store.join("0c5x-openjdk-17.0.9+9/lib/openjdk"), dir.join("profile/lib/openjdk")).unwrap();
+
+        let mut report = ScanReport::default();
+        let candidates = nix_store(&store, Some(&dir.join("profile/lib/openjdk")), &mut report);
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(candidates[0].path, store.join("0c5x-openjdk-17.0.9+9/lib/openjdk"));
+        assert_eq!(candidates[0].name, "openjdk-17.0.9+9");
+        assert_eq!(candidates[0].version, "17.0.9+9");
+        assert_eq!(candidates[0].vendor, Vendor::Unknown);
+        assert_eq!(candidates[1].path, store.join("1d6y-temurin-bin-21.0.1"));
+        assert_eq!(candidates[1].vendor, Vendor::Temurin);
+        assert_eq!(candidates[2].path, fs::canonicalize(&candidates[0].path).unwrap());
+        assert_eq!(candidates[2].tags, vec!["nix profile"]);
+    }
+
+    #[test]
+    fn test_linuxbrew_formulae() {
+        let dir = TempDir::new("linuxbrew");
+        fs::create_dir_all(dir.join("Cellar/openjdk@17/17.0.9/libexec")).unwrap();
+        fs::create_dir_all(dir.join("Cellar/openjdk/21.0.1_1/libexec")).unwrap();
+        fs::create_dir_all(dir.join("opt")).unwrap();
+        std::os::unix::fs::symlink(dir.join("Cellar/openjdk@17/17.0.9"), dir.join("opt/openjdk@17")).unwrap();
+        std::os::unix::fs::symlink(dir.join("Cellar/openjdk/21.0.1_1"), dir.join("opt/openjdk")).unwrap();
+        std::os::unix::fs::symlink(dir.join("Cellar/maven/3.9.5"), dir.join("opt/maven")).unwrap();
+
+        let mut report = ScanReport::default();
+        let candidates = linuxbrew_formulae(&dir.join("opt"), &mut report);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].name, "openjdk");
+        assert_eq!(candidates[0].version, "21.0.1");
+        assert_eq!(candidates[1].name, "openjdk@17");
+        assert_eq!(candidates[1].path, fs::canonicalize(dir.join("Cellar/openjdk@17/17.0.9/libexec")).unwrap());
+    }
+
+    #[test]
+    fn test_jetbrains() {
+        let dir = TempDir::new("jetbrains");
+        fs::create_dir_all(dir.join("apps/intellij-idea-ultimate/jbr/bin")).unwrap();
+        fs::create_dir_all(dir.join("apps/IDEA-C/ch-0/233.11799.241/jbr/bin")).unwrap();
+        fs::create_dir_all(dir.join("apps/IDEA-C/ch-0/233.11799.241.plugins")).unwrap();
+        fs::create_dir_all(dir.join("opt/android-studio/jre/bin")).unwrap();
+        fs::create_dir_all(dir.join("opt/IntelliJ IDEA.app/Contents/jbr/Contents/Home/bin")).unwrap();
+        fs::create_dir_all(dir.join("opt/java/openjdk/bin")).unwrap();
+
+        let mut report = ScanReport::default();
+        let mut ides = toolbox_ides(&dir.join("apps"), &mut report);
+        ides.extend(["android-studio", "IntelliJ IDEA.app", "java"].iter()
+            .map(|ide| (ide.to_string(), dir.join("opt").join(ide))));
+        let candidates = ide_runtimes(ides);
+        assert_eq!(candidates.len(), 4);
+        assert_eq!(candidates[0].name, "IDEA-C 233.11799.241");
+        assert_eq!(candidates[0].path, dir.join("apps/IDEA-C/ch-0/233.11799.241/jbr"));
+        assert_eq!(candidates[1].name, "intellij-idea-ultimate");
+        assert_eq!(candidates[2].path, dir.join("opt/android-studio/jre"));
+        assert_eq!(candidates[3].path, dir.join("opt/IntelliJ IDEA.app/Contents/jbr/Contents/Home"));
+    }
+
+    #[test]
+    fn test_sdkman() {
+        let dir = TempDir::new("sdkman");
+        fs::create_dir_all(dir.join("17.0.9-tem")).unwrap();
+        fs::create_dir_all(dir.join("21.0.1-zulu")).unwrap();
+        std::os::unix::fs::symlink("21.0.1-zulu", dir.join("current")).unwrap();
+
+        let mut report = ScanReport::default();
+        let candidates = sdkman(&dir, &mut report);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].name, "17.0.9-tem");
+        assert_eq!(candidates[0].vendor, Vendor::Temurin);
+        assert!(candidates[0].tags.is_empty());
+        assert_eq!(candidates[1].path, dir.join("21.0.1-zulu"));
+        assert_eq!(candidates[1].tags, vec!["sdkman current"]);
+
+        assert!(sdkman(&dir.join("missing"), &mut report).is_empty());
+        assert!(report.locations.is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use roxmltree::{Document, Node};
+
+use crate::candidate::{java_version, Candidate};
+use crate::report::ScanReport;
+use crate::vendor::Vendor;
+
+// Where the user's home directory is found, which is also Maven's user.home
+#[cfg(unix)]
+const HOME_VARIABLE: &str = "HOME";
+#[cfg(target_os = "windows")]
+const HOME_VARIABLE: &str = "USERPROFILE";
+
+/// JDKs in the current user's Maven toolchains - ~/.m2/toolchains.xml, or
+/// %USERPROFILE%\.m2\toolchains.xml on Windows
+pub fn user_toolchains(report: &mut ScanReport) -> Vec<Candidate> {
+    match env::var_os(HOME_VARIABLE) {
+        Some(home) => maven_toolchains(&Path::new(&home).join(".m2").join("toolchains.xml"), report),
+        None => vec![],
+    }
+}
+
+/// JDKs listed in a Maven toolchains.xml - each jdk toolchain's jdkHome, with the version and
+/// vendor it provides used when the home has no release file
+pub fn maven_toolchains(file: &Path, report: &mut ScanReport) -> Vec<Candidate> {
+    let content = match fs::read_to_string(file) {
+        Ok(content) => content,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return vec![],
+        Err(error) => {
+            report.location(file, error);
+            return vec![];
+        }
+    };
+    match parse_toolchains(&content) {
+        Ok(candidates) => candidates,
+        Err(error) => {
+            report.location(file, error);
+            vec![]
+        }
+    }
+}
+
+pub fn parse_toolchains(content: &str) -> Result<Vec<Candidate>, roxmltree::Error> {
+    let document = Document::parse(content)?;
+    let candidates = document.root_element()
+        .children()
+        .filter(|toolchain| toolchain.has_tag_name("toolchain"))
+        .filter(|toolchain| child_text(*toolchain, &["type"]) == "jdk")
+        .filter_map(|toolchain| {
+            let home = interpolate(&child_text(toolchain, &["configuration", "jdkHome"]));
+            if home.is_empty() {
+                return None;
+            }
+            let path = PathBuf::from(home);
+            let vendor = match child_text(toolchain, &["provides", "vendor"]) {
+                vendor if vendor.is_empty() => Vendor::Unknown,
+                vendor => Vendor::parse(&vendor),
+            };
+            Some(Candidate {
+                name: path.file_name().unwrap_or_default().to_string_lossy().to_string(),
+                version: java_version(&child_text(toolchain, &["provides", "version"])),
+                vendor,
+                path,
+                ..Default::default()
+            })
+        })
+        .collect();
+    Ok(candidates)
+}
+
+// Trimmed text of the element at a path below a node, or empty if there isn't one
+fn child_text(node: Node, path: &[&str]) -> String {
+    path.iter()
+        .try_fold(node, |node, name| node.children().find(|child| child.has_tag_name(*name)))
+        .and_then(|node| node.text())
+        .map(|text| text.trim().to_string())
+        .unwrap_or_default()
+}
+
+// Expands the ${env.NAME} and ${user.home} expressions Maven allows in toolchains.xml
+fn interpolate(value: &str) -> String {
+    let mut result = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let end = match rest[start..].find('}') {
+            Some(end) => start + end,
+            None => break,
+        };
+        result.push_str(&rest[..start]);
+        let expression = &rest[start + 2..end];
+        let expanded = match expression.strip_prefix("env.") {
+            Some(variable) => env::var(variable).ok(),
+            None if expression == "user.home" => env::var(HOME_VARIABLE).ok(),
+            None => None,
+        };
+        result.push_str(&expanded.unwrap_or_else(|| rest[start..=end].to_string()));
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_toolchains() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <toolchains xmlns="http://maven.apache.org/TOOLCHAINS/1.1.0">
+              <toolchain>
+                <type>jdk</type>
+                <provides>
+                  <version>17</version>
+                  <vendor>temurin</vendor>
+                </provides>
+                <configuration>
+                  <jdkHome>/opt/jvms/jdk-17.0.9+9</jdkHome>
+                </configuration>
+              </toolchain>
+              <toolchain>
+                <type>jdk</type>
+                <provides>
+                  <version>[11,12)</version>
+                </provides>
+                <configuration>
+                  <jdkHome>${env.JAVALOCATE_TEST_HOME}/jdk-11</jdkHome>
+                </configuration>
+              </toolchain>
+              <toolchain>
+                <type>protobuf</type>
+                <configuration>
+                  <protocExecutable>/usr/bin/protoc</protocExecutable>
+                </configuration>
+              </toolchain>
+            </toolchains>"#;
+        env::set_var("JAVALOCATE_TEST_HOME", "/opt/jvms");
+
+        let candidates = parse_toolchains(content).unwrap();
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].path, PathBuf::from("/opt/jvms/jdk-17.0.9+9"));
+        assert_eq!(candidates[0].name, "jdk-17.0.9+9");
+        assert_eq!(candidates[0].version, "17");
+        assert_eq!(candidates[0].vendor, Vendor::Temurin);
+        assert_eq!(candidates[1].path, PathBuf::from("/opt/jvms/jdk-11"));
+        assert_eq!(candidates[1].version, "");
+        assert_eq!(candidates[1].vendor, Vendor::Unknown);
+
+        assert!(parse_toolchains("<toolchains>").is_err());
+    }
+
+    #[test]
+    fn test_interpolate() {
+        env::set_var("JAVALOCATE_TEST_JDK", "/opt/jdk");
+        assert_eq!(interpolate("${env.JAVALOCATE_TEST_JDK}/17"), "/opt/jdk/17");
+        assert_eq!(interpolate("${env.JAVALOCATE_TEST_UNSET}/17"), "${env.JAVALOCATE_TEST_UNSET}/17");
+        assert_eq!(interpolate("/opt/${unclosed"), "/opt/${unclosed");
+        assert_eq!(interpolate("/opt/jdk-17"), "/opt/jdk-17");
+    }
+}
@@ -0,0 +1,65 @@
+use std::fs;
+use std::path::Path;
+
+#[cfg(target_os = "windows")]
+const EXECUTABLE_EXTENSION: &str = "exe";
+
+/// Names of the tools in a JVM home's bin directory (e.g. java, javac, jlink, native-image),
+/// sorted and without any executable extension
+pub fn home_tools(home: &Path) -> Vec<String> {
+    let entries = match fs::read_dir(home.join("bin")) {
+        Ok(entries) => entries,
+        Err(_) => return vec![],
+    };
+    let mut tools: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| is_executable(path))
+        .filter_map(|path| tool_name(&path))
+        .collect();
+    tools.sort();
+    tools
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    // Follows links, as some distributions link tools in from elsewhere
+    fs::metadata(path).is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(target_os = "windows")]
+fn is_executable(path: &Path) -> bool {
+    path.is_file() && path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case(EXECUTABLE_EXTENSION))
+}
+
+#[cfg(unix)]
+fn tool_name(path: &Path) -> Option<String> {
+    Some(path.file_name()?.to_string_lossy().to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn tool_name(path: &Path) -> Option<String> {
+    Some(path.file_stem()?.to_string_lossy().to_string())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::fixture::TempDir;
+    use std::os::unix::fs::PermissionsExt;
+
+    #[test]
+    fn test_home_tools() {
+        let home = TempDir::new("tools");
+        fs::create_dir_all(home.join("bin/subdir")).unwrap();
+        for (name, mode) in [("java", 0o755), ("jlink", 0o755), ("jfr", 0o755), ("README", 0o644)] {
+            fs::write(home.join("bin").join(name), "").unwrap();
+            fs::set_permissions(home.join("bin").join(name), fs::Permissions::from_mode(mode)).unwrap();
+        }
+        std::os::unix::fs::symlink(home.join("bin/java"), home.join("bin/javaw")).unwrap();
+
+        assert_eq!(home_tools(&home), vec!["java", "javaw", "jfr", "jlink"]);
+        assert!(home_tools(&home.join("missing")).is_empty());
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+
+/// Canonical JVM distribution, normalised from the release file implementor details
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Vendor {
+    Temurin,
+    Zulu,
+    Corretto,
+    Liberica,
+    GraalVm,
+    Semeru,
+    Microsoft,
+    Oracle,
+    SapMachine,
+    Dragonwell,
+    JetBrains,
+    RedHat,
+    Debian,
+    Ubuntu,
+    Other(String),
+    #[default]
+    Unknown,
+}
+
+// Lower case markers for each vendor, checked in order against the implementor details
+const MARKERS: [(&[&str], Vendor); 14] = [
+    (&["graalvm"], Vendor::GraalVm),
+    (&["temurin", "adoptium", "adoptopenjdk"], Vendor::Temurin),
+    (&["zulu", "azul"], Vendor::Zulu),
+    (&["corretto", "amazon"], Vendor::Corretto),
+    (&["liberica", "bellsoft"], Vendor::Liberica),
+    (&["semeru", "ibm", "international business machines"], Vendor::Semeru),
+    (&["microsoft"], Vendor::Microsoft),
+    (&["sapmachine", "sap se"], Vendor::SapMachine),
+    (&["dragonwell", "alibaba"], Vendor::Dragonwell),
+    (&["jetbrains"], Vendor::JetBrains),
+    (&["red hat", "redhat"], Vendor::RedHat),
+    (&["debian"], Vendor::Debian),
+    (&["ubuntu"], Vendor::Ubuntu),
+    (&["oracle"], Vendor::Oracle),
+];
+
+// Names accepted by --vendor, compared with spaces, dashes and underscores removed
+const ALIASES: [(&[&str], Vendor); 14] = [
+    (&["temurin", "adoptium", "adoptopenjdk", "eclipse"], Vendor::Temurin),
+    (&["zulu", "azul"], Vendor::Zulu),
+    (&["corretto", "amazon"], Vendor::Corretto),
+    (&["liberica", "bellsoft"], Vendor::Liberica),
+    (&["graalvm", "graal"], Vendor::GraalVm),
+    (&["semeru", "ibm", "openj9"], Vendor::Semeru),
+    (&["microsoft", "ms"], Vendor::Microsoft),
+    (&["oracle"], Vendor::Oracle),
+    (&["sapmachine", "sap"], Vendor::SapMachine),
+    (&["dragonwell", "alibaba"], Vendor::Dragonwell),
+    (&["jetbrains", "jbr"], Vendor::JetBrains),
+    (&["redhat", "rh"], Vendor::RedHat),
+    (&["debian"], Vendor::Debian),
+    (&["ubuntu"], Vendor::Ubuntu),
+];
+
+impl Vendor {
+    /// Normalises the release file IMPLEMENTOR, IMPLEMENTOR_VERSION and JAVA_RUNTIME_VERSION values
+    pub fn detect(implementor: &str, implementor_version: &str, runtime_version: &str) -> Vendor {
+        // Distribution packages often only identify themselves in the runtime version
+        // - e.g. 17.0.9+9-Ubuntu-122.04 with an implementor of "Private Build"
+        let details = format!("{} {} {}", implementor, implementor_version, runtime_version).to_lowercase();
+        for (markers, vendor) in MARKERS.iter() {
+            if markers.iter().any(|marker| details.contains(marker)) {
+                return vendor.clone();
+            }
+        }
+        // Builds without a vendor, such as nixpkgs', may give N/A
+        if implementor.is_empty() || implementor == "N/A" {
+            Vendor::Unknown
+        } else {
+            Vendor::Other(implementor.to_string())
+        }
+    }
+
+    /// Resolves a user supplied vendor name, keeping unrecognised names as-is
+    pub fn parse(value: &str) -> Vendor {
+        let normalised: String = value.to_lowercase()
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_' | '!'))
+            .collect();
+        ALIASES.iter()
+            .find(|(aliases, _)| aliases.contains(&normalised.as_str()))
+            .map(|(_, vendor)| vendor.clone())
+            .unwrap_or_else(|| Vendor::Other(value.to_string()))
+    }
+}
+
+impl fmt::Display for Vendor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Vendor::Temurin => "Temurin",
+            Vendor::Zulu => "Zulu",
+            Vendor::Corretto => "Corretto",
+            Vendor::Liberica => "Liberica",
+            Vendor::GraalVm => "GraalVM",
+            Vendor::Semeru => "Semeru",
+            Vendor::Microsoft => "Microsoft",
+            Vendor::Oracle => "Oracle",
+            Vendor::SapMachine => "SapMachine",
+            Vendor::Dragonwell => "Dragonwell",
+            Vendor::JetBrains => "JetBrains",
+            Vendor::RedHat => "Red Hat",
+            Vendor::Debian => "Debian",
+            Vendor::Ubuntu => "Ubuntu",
+            Vendor::Other(name) => name,
+            Vendor::Unknown => "Unknown",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(Vendor::detect("Eclipse Adoptium", "Temurin-17.0.2+8", "17.0.2+8"), Vendor::Temurin);
+        assert_eq!(Vendor::detect("AdoptOpenJDK", "AdoptOpenJDK", "11.0.11+9"), Vendor::Temurin);
+        assert_eq!(Vendor::detect("Azul Systems, Inc.", "Zulu17.44+53-CA", "17.0.8.1+1-LTS"), Vendor::Zulu);
+        assert_eq!(Vendor::detect("Amazon.com Inc.", "Corretto-17.0.9.8.1", "17.0.9+8-LTS"), Vendor::Corretto);
+        assert_eq!(Vendor::detect("BellSoft", "", "17.0.9+11-LTS"), Vendor::Liberica);
+        assert_eq!(Vendor::detect("GraalVM Community", "GraalVM CE 17.0.9+9.1", "17.0.9+9-jvmci-23.0-b22"), Vendor::GraalVm);
+        assert_eq!(Vendor::detect("Oracle Corporation", "Oracle GraalVM 21.0.1+12.1", "21.0.1+12-jvmci-23.1-b19"), Vendor::GraalVm);
+        assert_eq!(Vendor::detect("IBM Corporation", "IBM Semeru Runtime Open Edition", "17.0.9+9"), Vendor::Semeru);
+        assert_eq!(Vendor::detect("Microsoft", "Microsoft-8552938", "17.0.9+8-LTS"), Vendor::Microsoft);
+        assert_eq!(Vendor::detect("Oracle Corporation", "", "17.0.2+8-86"), Vendor::Oracle);
+        assert_eq!(Vendor::detect("SAP SE", "SapMachine", "17.0.9+9-LTS"), Vendor::SapMachine);
+        assert_eq!(Vendor::detect("Alibaba", "(Alibaba Dragonwell Extended Edition)", "17.0.9+8"), Vendor::Dragonwell);
+        assert_eq!(Vendor::detect("JetBrains s.r.o.", "", "17.0.9+8-b1166.2"), Vendor::JetBrains);
+        assert_eq!(Vendor::detect("Red Hat, Inc.", "", "17.0.9+9-LTS"), Vendor::RedHat);
+        assert_eq!(Vendor::detect("Debian", "", "17.0.9+9-Debian-1deb12u1"), Vendor::Debian);
+        assert_eq!(Vendor::detect("Private Build", "", "17.0.9+9-Ubuntu-122.04"), Vendor::Ubuntu);
+        assert_eq!(Vendor::detect("Homebrew", "", "21.0.1"), Vendor::Other("Homebrew".to_string()));
+        assert_eq!(Vendor::detect("", "", ""), Vendor::Unknown);
+        assert_eq!(Vendor::detect("N/A", "", "17.0.9+9-nixos"), Vendor::Unknown);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Vendor::parse("temurin"), Vendor::Temurin);
+        assert_eq!(Vendor::parse("Red Hat"), Vendor::RedHat);
+        assert_eq!(Vendor::parse("red-hat"), Vendor::RedHat);
+        assert_eq!(Vendor::parse("GraalVM"), Vendor::GraalVm);
+        assert_eq!(Vendor::parse("SapMachine"), Vendor::SapMachine);
+        assert_eq!(Vendor::parse("jbr"), Vendor::JetBrains);
+        assert_eq!(Vendor::parse("Homebrew"), Vendor::Other("Homebrew".to_string()));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Vendor::RedHat.to_string(), "Red Hat");
+        assert_eq!(Vendor::parse(&Vendor::GraalVm.to_string()), Vendor::GraalVm);
+        assert_eq!(Vendor::Other("Homebrew".to_string()).to_string(), "Homebrew");
+    }
+}
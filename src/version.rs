@@ -0,0 +1,286 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Java version as reported in a release file, covering both the JEP 322 scheme
+/// (e.g. 17.0.2+8, 21-ea) and the legacy 1.x scheme (e.g. 1.8.0_292-b10).
+#[derive(Clone, Debug)]
+pub struct JavaVersion {
+    pub feature: u32,
+    pub interim: u32,
+    pub update: u32,
+    pub patch: u32,
+    pub extra: Vec<u32>,
+    pub pre: Option<String>,
+    pub build: Option<u32>,
+    pub opt: Option<String>,
+    pub legacy: bool,
+    precision: usize,
+}
+
+impl JavaVersion {
+    pub fn parse(value: &str) -> Option<JavaVersion> {
+        let value = value.trim().trim_matches('"');
+
+        // Legacy versions carry a "1." prefix - e.g. 1.8.0_292 is feature 8, update 292
+        let (legacy, body) = match value.strip_prefix("1.") {
+            Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => (true, rest),
+            _ => (false, value),
+        };
+
+        // Version number runs until the first pre-release, build or optional marker
+        let end = body
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '_'))
+            .unwrap_or(body.len());
+        let (number, mut rest) = body.split_at(end);
+        let number = number.trim_end_matches(['.', '_']);
+        if number.is_empty() {
+            return None;
+        }
+        let mut components = Vec::new();
+        for part in number.split(['.', '_']) {
+            components.push(part.parse::<u32>().ok()?);
+        }
+
+        let mut pre = None;
+        let mut build = None;
+        if let Some(tail) = rest.strip_prefix('-') {
+            let end = tail.find(['+', '-']).unwrap_or(tail.len());
+            let (token, tail) = tail.split_at(end);
+            match legacy_build(token) {
+                Some(number) if legacy => build = Some(number),
+                _ => pre = Some(token.to_string()),
+            }
+            rest = tail;
+        }
+        if legacy && build.is_none() {
+            // Legacy early access builds - e.g. 1.8.0-ea-b03
+            if let Some(number) = rest.strip_prefix('-').and_then(legacy_build) {
+                build = Some(number);
+                rest = "";
+            }
+        }
+        if let Some(tail) = rest.strip_prefix('+') {
+            let end = tail.find(|c: char| !c.is_ascii_digit()).unwrap_or(tail.len());
+            let (digits, tail) = tail.split_at(end);
+            build = digits.parse::<u32>().ok();
+            rest = tail;
+        }
+        let opt = rest.trim_start_matches(['-', '+']);
+        let opt = if opt.is_empty() { None } else { Some(opt.to_string()) };
+
+        let mut version = JavaVersion::from_components(&components);
+        version.pre = pre;
+        version.build = build;
+        version.opt = opt;
+        version.legacy = legacy;
+        Some(version)
+    }
+
+    fn from_components(components: &[u32]) -> JavaVersion {
+        let component = |index: usize| *components.get(index).unwrap_or(&0);
+        JavaVersion {
+            feature: component(0),
+            interim: component(1),
+            update: component(2),
+            patch: component(3),
+            extra: components.iter().skip(4).cloned().collect(),
+            precision: components.len(),
+            pre: None,
+            build: None,
+            opt: None,
+            legacy: false,
+        }
+    }
+
+    pub fn is_prerelease(&self) -> bool {
+        self.pre.is_some()
+    }
+
+    /// Number of version number components that were given (e.g. 2 for "17.0")
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    /// Version number cut down to the given number of components, without pre-release or build
+    pub fn truncated(&self, precision: usize) -> JavaVersion {
+        let components: Vec<u32> = (0..precision).map(|i| self.component(i)).collect();
+        let mut version = JavaVersion::from_components(&components);
+        version.legacy = self.legacy;
+        version
+    }
+
+    /// Compares only the version number components present in `other`
+    pub fn cmp_prefix(&self, other: &JavaVersion) -> Ordering {
+        self.truncated(other.precision()).cmp_number(other)
+    }
+
+    fn component(&self, index: usize) -> u32 {
+        match index {
+            0 => self.feature,
+            1 => self.interim,
+            2 => self.update,
+            3 => self.patch,
+            _ => *self.extra.get(index - 4).unwrap_or(&0),
+        }
+    }
+
+    fn cmp_number(&self, other: &JavaVersion) -> Ordering {
+        let max_size = std::cmp::max(self.precision(), other.precision());
+        (0..max_size)
+            .map(|i| self.component(i).cmp(&other.component(i)))
+            .find(|compare| compare.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+fn legacy_build(token: &str) -> Option<u32> {
+    token.strip_prefix('b')?.parse::<u32>().ok()
+}
+
+impl Ord for JavaVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_number(other)
+            // Pre-release builds come before the release itself
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(b),
+            })
+            .then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+impl PartialOrd for JavaVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for JavaVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for JavaVersion {}
+
+impl fmt::Display for JavaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numbers: Vec<String> = (0..self.precision).map(|i| self.component(i).to_string()).collect();
+        if self.legacy {
+            // e.g. 1.8.0_292-b10
+            write!(f, "1.{}", numbers.iter().take(2).cloned().collect::<Vec<_>>().join("."))?;
+            if numbers.len() > 2 {
+                write!(f, "_{}", numbers[2..].join("."))?;
+            }
+            if let Some(pre) = &self.pre {
+                write!(f, "-{}", pre)?;
+            }
+            if let Some(build) = self.build {
+                write!(f, "-b{:02}", build)?;
+            }
+        } else {
+            write!(f, "{}", numbers.join("."))?;
+            if let Some(pre) = &self.pre {
+                write!(f, "-{}", pre)?;
+            }
+            if let Some(build) = self.build {
+                write!(f, "+{}", build)?;
+            }
+        }
+        if let Some(opt) = &self.opt {
+            write!(f, "-{}", opt)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(value: &str) -> JavaVersion {
+        JavaVersion::parse(value).unwrap()
+    }
+
+    #[test]
+    fn test_parse_jep322() {
+        let ver = version("17.0.2+8");
+        assert_eq!((ver.feature, ver.interim, ver.update, ver.patch), (17, 0, 2, 0));
+        assert_eq!(ver.build, Some(8));
+        assert_eq!(ver.pre, None);
+
+        let ver = version("21-ea");
+        assert_eq!(ver.feature, 21);
+        assert_eq!(ver.pre, Some("ea".to_string()));
+        assert!(ver.is_prerelease());
+
+        let ver = version("17.0.8.1+1-LTS");
+        assert_eq!(ver.patch, 1);
+        assert_eq!(ver.build, Some(1));
+        assert_eq!(ver.opt, Some("LTS".to_string()));
+
+        let ver = version("17-internal+0-adhoc.root.jdk");
+        assert_eq!(ver.pre, Some("internal".to_string()));
+        assert_eq!(ver.build, Some(0));
+        assert_eq!(ver.opt, Some("adhoc.root.jdk".to_string()));
+    }
+
+    #[test]
+    fn test_parse_legacy() {
+        let ver = version("1.8.0_292-b10");
+        assert!(ver.legacy);
+        assert_eq!((ver.feature, ver.interim, ver.update), (8, 0, 292));
+        assert_eq!(ver.build, Some(10));
+        assert_eq!(ver.pre, None);
+
+        let ver = version("1.8.0-ea-b03");
+        assert_eq!(ver.pre, Some("ea".to_string()));
+        assert_eq!(ver.build, Some(3));
+
+        assert_eq!(version("1.8").feature, 8);
+        assert_eq!(version("\"1.7.0_80\"").update, 80);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(JavaVersion::parse("").is_none());
+        assert!(JavaVersion::parse("ea").is_none());
+        assert!(JavaVersion::parse("17..2").is_none());
+        assert_eq!(version("17.0.1_bau").opt, Some("bau".to_string()));
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(version("21-ea") < version("21"));
+        assert!(version("21-ea") > version("17.0.9"));
+        assert!(version("17.0.2+8") < version("17.0.2+9"));
+        assert!(version("1.8.0_292-b10") < version("1.8.0_302-b08"));
+        assert!(version("1.8.0_292") < version("11"));
+        assert!(version("17.0.1.1") > version("17.0.1"));
+        assert_eq!(version("1.8"), version("8"));
+        assert_eq!(version("17"), version("17.0.0"));
+        assert_eq!(version("17.0.2+8-LTS"), version("17.0.2+8"));
+    }
+
+    #[test]
+    fn test_prefix() {
+        let ver = version("17.0.2");
+        assert_eq!(ver.truncated(1), version("17"));
+        assert_eq!(ver.truncated(2), version("17.0"));
+        assert_eq!(ver.cmp_prefix(&version("17")), Ordering::Equal);
+        assert_eq!(ver.cmp_prefix(&version("17.1")), Ordering::Less);
+        assert_eq!(ver.cmp_prefix(&version("17.0.1")), Ordering::Greater);
+        assert_eq!(version("1.8.0_292").cmp_prefix(&version("8")), Ordering::Equal);
+        assert_eq!(version("1.8.0_292").cmp_prefix(&version("1.8")), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(version("17.0.2+8").to_string(), "17.0.2+8");
+        assert_eq!(version("21-ea+3").to_string(), "21-ea+3");
+        assert_eq!(version("1.8.0_292-b10").to_string(), "1.8.0_292-b10");
+        assert_eq!(version("1.8").to_string(), "1.8");
+    }
+}